@@ -0,0 +1,326 @@
+//! `aoc compare`: runs one day on every input in a directory, such as the inputs of everyone on
+//! the team, and prints a table of the answers and timings per input.
+//!
+//! Every input runs in a child process of its own, `aoc run <day> --input <path> --format json`, so
+//! that a panic is reported rather than ending the comparison, and an input that takes longer than
+//! the time budget can be killed before the next input starts.
+
+use std::io::Read;
+use std::path::Path;
+use std::process::{Child, Command, ExitCode, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use common::answers::{self, Recorded};
+use common::runner::format_duration;
+use common::Answer;
+
+use crate::days;
+
+/// The file in the inputs directory with the expected answers, in the same format as a day's
+/// `answers.txt`, e.g. `alice.txt 6942 297`.
+const ANSWERS: &str = "answers.txt";
+
+/// How often to check whether the child running an input has finished.
+const POLL: Duration = Duration::from_millis(5);
+
+/// What became of running one input.
+enum Outcome {
+    Solved(Solved),
+    Unreadable(String),
+    /// Where the input is invalid and why, e.g. `3:5: expected a digit`
+    Invalid(String),
+    Panicked(String),
+    /// The child ended some other way, e.g. it was killed by a signal
+    Crashed(String),
+    OverBudget,
+}
+
+/// The answers to the part(s) run, and how long parsing and solving took.
+struct Solved {
+    answers: Vec<(u8, String)>,
+    time: Duration,
+}
+
+/// A row of the table: the input's name, its answers and time, and whether it is fine.
+struct Row {
+    input: String,
+    answers: [String; 2],
+    time: String,
+    status: String,
+    ok: bool,
+}
+
+/// Reads all of `pipe` on a thread of its own, so the child never blocks on a full pipe.
+fn read_pipe(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut text = String::new();
+        // Whatever was read before an error is all there is to report
+        let _ = pipe.read_to_string(&mut text);
+        text
+    })
+}
+
+/// Waits for `child` to exit for at most `budget`, and kills it if it doesn't.
+fn wait(child: &mut Child, budget: Duration) -> Option<ExitStatus> {
+    let start = Instant::now();
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => return Some(status),
+            Ok(None) if start.elapsed() < budget => thread::sleep(POLL),
+            _ => break,
+        }
+    }
+    // Fails only if it exited in the meantime, which is just as good
+    let _ = child.kill();
+    let _ = child.wait();
+    None
+}
+
+/// The answers and times in what `aoc run --format json` printed.
+fn parse_records(json: &str) -> Option<Solved> {
+    let records: Vec<serde_json::Value> = serde_json::from_str(json).ok()?;
+    let mut answers = Vec::new();
+    let mut time = Duration::ZERO;
+    for record in &records {
+        let answer = match &record["answer"] {
+            serde_json::Value::String(text) => text.clone(),
+            serde_json::Value::Null => Answer::Unsolvable.to_string(),
+            value => value.to_string(),
+        };
+        answers.push((record["part"].as_u64()? as u8, answer));
+        time += Duration::from_nanos(record["solve_time_ns"].as_u64()?);
+    }
+    // Both parts share the same parse
+    let parse_time = records.first()?["parse_time_ns"].as_u64()?;
+    time += Duration::from_nanos(parse_time);
+    Some(Solved { answers, time })
+}
+
+/// What went wrong, from what a failed `aoc run` printed to stderr.
+fn failure(day: u8, path: &str, status: ExitStatus, stderr: &str) -> Outcome {
+    // The message of a panic is on the line after the location
+    let mut lines = stderr.lines();
+    if lines.any(|line| line.contains(" panicked at ")) {
+        let message = lines.next().unwrap_or("unknown panic");
+        return Outcome::Panicked(message.to_string());
+    }
+    // Errors are reported as `Day 07: <path>: <error>`, and invalid inputs as
+    // `Day 07: <path>:<line>:<column>: <error>`
+    let prefix = format!("Day {:02}: {}", day, path);
+    let first = stderr.lines().next().unwrap_or_default();
+    match first.strip_prefix(&prefix) {
+        Some(rest) => match rest.strip_prefix(": ") {
+            Some(err) => Outcome::Unreadable(err.to_string()),
+            None => Outcome::Invalid(rest.trim_start_matches(':').to_string()),
+        },
+        None => Outcome::Crashed(status.to_string()),
+    }
+}
+
+/// Runs `day` on the input at `path` in a child process, killing it after `budget`.
+fn run_input(day: u8, path: &Path, part: Option<u8>, budget: Duration) -> Outcome {
+    let path = path.to_string_lossy();
+    let mut command = match std::env::current_exe() {
+        Ok(exe) => Command::new(exe),
+        Err(err) => return Outcome::Crashed(format!("can't find the aoc executable: {}", err)),
+    };
+    command
+        .args(["run", &day.to_string()])
+        .args(["--input", &path, "--format", "json"])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Some(part) = part {
+        command.args(["--part", &part.to_string()]);
+    }
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(err) => return Outcome::Crashed(format!("can't start aoc: {}", err)),
+    };
+
+    let stdout = read_pipe(child.stdout.take().expect("stdout is piped"));
+    let stderr = read_pipe(child.stderr.take().expect("stderr is piped"));
+    let status = wait(&mut child, budget);
+    let (stdout, stderr) = (stdout.join().unwrap(), stderr.join().unwrap());
+    match status {
+        None => Outcome::OverBudget,
+        Some(status) if status.success() => match parse_records(&stdout) {
+            Some(solved) => Outcome::Solved(solved),
+            None => Outcome::Crashed(format!("unexpected output '{}'", stdout.trim())),
+        },
+        Some(status) => failure(day, &path, status, &stderr),
+    }
+}
+
+/// Turns the outcome for `input` into a row, checking the answers against `recorded` if given.
+fn row(input: String, outcome: Outcome, recorded: Option<&Recorded>, budget: Duration) -> Row {
+    let failed = |input, time: String, status: String| Row {
+        input,
+        answers: ["-".to_string(), "-".to_string()],
+        time,
+        status,
+        ok: false,
+    };
+    let solved = match outcome {
+        Outcome::Solved(solved) => solved,
+        Outcome::Unreadable(err) => return failed(input, "-".to_string(), err),
+        Outcome::Invalid(err) => {
+            let status = format!("invalid input at {}", err);
+            return failed(input, "-".to_string(), status);
+        }
+        Outcome::Panicked(message) => {
+            return failed(input, "-".to_string(), format!("panicked: {}", message))
+        }
+        Outcome::Crashed(reason) => {
+            return failed(input, "-".to_string(), format!("crashed: {}", reason))
+        }
+        Outcome::OverBudget => {
+            let time = format!("> {}", format_duration(budget));
+            return failed(input, time, "over budget".to_string());
+        }
+    };
+
+    let mut answers = ["-".to_string(), "-".to_string()];
+    let mut wrong = Vec::new();
+    let mut checked = false;
+    for (part, answer) in solved.answers {
+        let idx = part as usize - 1;
+        match recorded.and_then(|recorded| recorded.expected(part)) {
+            Some(expected) if expected != answer => {
+                answers[idx] = format!("{} (expected {})", answer, expected);
+                wrong.push(format!("part {}", part));
+            }
+            Some(_) => {
+                answers[idx] = answer;
+                checked = true;
+            }
+            None => answers[idx] = answer,
+        }
+    }
+
+    let status = match (wrong.is_empty(), checked) {
+        (false, _) => format!("wrong {}", wrong.join(" and ")),
+        (true, true) => "ok".to_string(),
+        (true, false) => "no recorded answers".to_string(),
+    };
+    Row {
+        input,
+        answers,
+        time: format_duration(solved.time),
+        status,
+        ok: wrong.is_empty(),
+    }
+}
+
+fn print_table(rows: &[Row]) {
+    let width = |header: &str, cell: fn(&Row) -> &str| {
+        rows.iter()
+            .map(|row| cell(row).chars().count())
+            .chain([header.len()])
+            .max()
+            .unwrap_or(0)
+    };
+    let widths = [
+        width("Input", |row| &row.input),
+        width("Part 1", |row| &row.answers[0]),
+        width("Part 2", |row| &row.answers[1]),
+        width("Time", |row| &row.time),
+    ];
+    let print_row = |cells: [&str; 5]| {
+        println!(
+            "{:<w0$}  {:<w1$}  {:<w2$}  {:>w3$}  {}",
+            cells[0],
+            cells[1],
+            cells[2],
+            cells[3],
+            cells[4],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+        );
+    };
+
+    print_row(["Input", "Part 1", "Part 2", "Time", "Status"]);
+    for row in rows {
+        print_row([
+            &row.input,
+            &row.answers[0],
+            &row.answers[1],
+            &row.time,
+            &row.status,
+        ]);
+    }
+}
+
+/// Runs `day` on every input in `dir`, one at a time, and prints how each of them did. Fails if any
+/// input could not be solved within `budget`, or got an answer other than the recorded one.
+pub fn compare(day: u8, dir: &Path, part: Option<u8>, budget: Duration) -> ExitCode {
+    if !days::DAYS.contains(&day) {
+        eprintln!("day {} is not implemented", day);
+        return ExitCode::FAILURE;
+    }
+
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) => {
+            eprintln!("{}: {}", dir.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+    // Every file is an input, except for hidden files and the expected answers
+    let mut inputs: Vec<String> = entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let name = entry.file_name().into_string().ok()?;
+            let is_input =
+                entry.file_type().ok()?.is_file() && !name.starts_with('.') && name != ANSWERS;
+            is_input.then_some(name)
+        })
+        .collect();
+    inputs.sort();
+    if inputs.is_empty() {
+        eprintln!("{}: no inputs to compare", dir.display());
+        return ExitCode::FAILURE;
+    }
+
+    let answers_path = dir.join(ANSWERS);
+    let recorded = match answers::load(&answers_path.to_string_lossy()) {
+        Ok(Some(text)) => match answers::parse(&text) {
+            Ok(recorded) => recorded,
+            Err(err) => {
+                eprintln!(
+                    "{}:{}:{}: {}",
+                    answers_path.display(),
+                    err.line,
+                    err.column,
+                    err.message
+                );
+                return ExitCode::FAILURE;
+            }
+        },
+        Ok(None) => Vec::new(),
+        Err(err) => {
+            eprintln!("{}: {}", answers_path.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let rows: Vec<Row> = inputs
+        .into_iter()
+        .map(|input| {
+            let outcome = run_input(day, &dir.join(&input), part, budget);
+            let expected = recorded.iter().find(|entry| entry.input == input);
+            row(input, outcome, expected, budget)
+        })
+        .collect();
+    print_table(&rows);
+
+    let failures = rows.iter().filter(|row| !row.ok).count();
+    println!("{} inputs, {} failures", rows.len(), failures);
+    match failures {
+        0 => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
@@ -0,0 +1,82 @@
+use common::generate::{self, Generate};
+use common::runner::{self, Run};
+use common::{ParseError, Solution};
+
+/// Generates the dispatch table: a list of the registered days, plus `run`, `variants` and
+/// `generate` functions that match on the day number and call into the corresponding crate's
+/// [`Solution`](common::Solution) and [`Generate`] implementations.
+macro_rules! days {
+    ($($day:literal => $solution:path),* $(,)?) => {
+        /// All days that are linked into the runner, in ascending order.
+        pub const DAYS: &[u8] = &[$($day),*];
+
+        /// Runs the requested part(s) of `day` on the puzzle input `input`, solving the part that
+        /// `variant` belongs to with that variant if given. Panics if `day` has no such variant.
+        ///
+        /// Returns `None` if `day` is not registered.
+        pub fn run(
+            day: u8,
+            input: &str,
+            part: Option<u8>,
+            variant: Option<&str>,
+        ) -> Option<Result<Run, ParseError>> {
+            let _span = tracing::info_span!("day", day).entered();
+            match (day, variant) {
+                $(($day, None) => Some(runner::run::<$solution>(input, part)),
+                ($day, Some(name)) => Some(runner::run_variant::<$solution>(input, part, name)),)*
+                _ => None,
+            }
+        }
+
+        /// The variants of `day`, as the part they solve and their name.
+        ///
+        /// Returns `None` if `day` is not registered.
+        pub fn variants(day: u8) -> Option<Vec<(u8, &'static str)>> {
+            match day {
+                $($day => Some(
+                    <$solution as Solution>::variants()
+                        .iter()
+                        .map(|variant| (variant.part, variant.name))
+                        .collect(),
+                ),)*
+                _ => None,
+            }
+        }
+
+        /// Generates a random input for `day` from `seed`, of the day's default size unless `size`
+        /// is given.
+        ///
+        /// Returns `None` if `day` is not registered.
+        pub fn generate(day: u8, seed: u64, size: Option<usize>) -> Option<String> {
+            match day {
+                $($day => Some(generate::generate::<$solution>(
+                    seed,
+                    size.unwrap_or(<$solution as Generate>::DEFAULT_SIZE),
+                )),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+days! {
+    1 => day01::Day01,
+    2 => day02::Day02,
+    3 => day03::Day03,
+    4 => day04::Day04,
+    5 => day05::Day05,
+    6 => day06::Day06,
+    7 => day07::Day07,
+    8 => day08::Day08,
+    9 => day09::Day09,
+    10 => day10::Day10,
+    11 => day11::Day11,
+    12 => day12::Day12,
+    13 => day13::Day13,
+    14 => day14::Day14,
+    15 => day15::Day15,
+    16 => day16::Day16,
+    17 => day17::Day17,
+    18 => day18::Day18,
+    19 => day19::Day19,
+}
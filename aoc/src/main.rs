@@ -0,0 +1,880 @@
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use client::{Client, Config, Hint, Outcome, Submission};
+use common::alloc::Allocations;
+use common::runner::{format_duration, Run};
+use common::{answers, read_text, Answer, ParseError};
+use tracing_subscriber::filter::{LevelFilter, Targets};
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::prelude::*;
+
+mod compare;
+mod days;
+mod pool;
+mod renders;
+mod selection;
+mod visualisers;
+
+use selection::Selection;
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: common::alloc::Counting = common::alloc::Counting;
+
+/// Root of the workspace, used to locate each day's checked-in `input.txt` and `answers.txt`.
+const WORKSPACE_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Log what the solutions are doing to stderr: `-v` for how long each parse and part took,
+    /// `-vv` for the intermediate values they log, `-vvv` for everything
+    #[arg(long, short, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Which logs to show, instead of `-v`: a default level and/or levels per day, e.g.
+    /// `day10=trace` or `info,day05=debug`
+    #[arg(long, global = true, value_name = "FILTER", conflicts_with = "verbose")]
+    log: Option<Targets>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one or more days
+    Run {
+        /// Days to run: a single day (`17`), a range (`3-7`), a list (`1,4,10-12`) or `all`
+        #[arg(default_value = "all")]
+        days: Selection,
+
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Input file to use instead of the day's `input.txt`, or `-` for stdin (only valid for a
+        /// single day)
+        #[arg(long)]
+        input: Option<String>,
+
+        /// Output format: `json` prints an array with an object per day and part, including the
+        /// parse and solve times
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        /// Number of days to run at the same time (defaults to the number of cores)
+        #[arg(long, short, value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: Option<u16>,
+
+        /// Run one day at a time, so the timings are not skewed by days competing for the CPU
+        #[arg(long, conflicts_with = "jobs")]
+        sequential: bool,
+
+        /// Solve the part this alternative implementation belongs to with it, e.g. `dfa` for day 1
+        /// (only valid for a single day)
+        #[arg(long)]
+        variant: Option<String>,
+    },
+    /// Download puzzle inputs into each day's `input.txt`, caching them locally
+    Fetch {
+        /// Days to download, in the same format as for `run`
+        #[arg(default_value = "all")]
+        days: Selection,
+    },
+    /// Submit an answer, computed by running the solution unless given with `--answer`
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Answer to submit, instead of running the solution
+        #[arg(long)]
+        answer: Option<String>,
+
+        /// Input file to compute the answer from instead of the day's `input.txt`, or `-` for stdin
+        #[arg(long, conflicts_with = "answer")]
+        input: Option<String>,
+
+        /// If the server does not accept answers yet, wait until it does instead of giving up
+        #[arg(long)]
+        wait: bool,
+    },
+    /// Check the recorded answers in each day's `answers.txt` against the current solutions
+    Verify {
+        /// Days to check, in the same format as for `run`
+        #[arg(default_value = "all")]
+        days: Selection,
+    },
+    /// Run a day on every input in a directory, e.g. everyone's on the team, and compare the
+    /// answers and timings
+    Compare {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Directory with the inputs, and optionally their expected answers in `answers.txt`
+        dir: PathBuf,
+
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Seconds an input may take before it is reported as over budget and killed
+        #[arg(long, value_name = "SECONDS", default_value_t = 15.0, value_parser = parse_budget)]
+        budget: f64,
+    },
+    /// Print a random puzzle input for a day, to stress test or benchmark the solutions with
+    Generate {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Seed for the generator: the same seed and size always give the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// How big the input is, usually the number of lines or the width of a grid (defaults to
+        /// about the size of the real input)
+        #[arg(long)]
+        size: Option<usize>,
+    },
+    /// Draw a day's puzzle as a PNG image or an animated GIF, into `OUT/dayNN.png` or `.gif`
+    Render {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Input file to draw instead of the day's `input.txt`, or `-` for stdin
+        #[arg(long)]
+        input: Option<String>,
+
+        /// Directory to write to
+        #[arg(long, default_value = "renders")]
+        out: PathBuf,
+
+        /// Width and height of a tile, in pixels
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u16).range(1..))]
+        scale: u16,
+
+        /// Write every frame as a PNG of its own, into `OUT/dayNN/`
+        #[arg(long)]
+        frames: bool,
+    },
+    /// Step through a day's simulation in the terminal, with pause, step, speed and scroll keys
+    Visualise {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Input file to step through instead of the day's `input.txt`, or `-` for stdin
+        #[arg(long)]
+        input: Option<String>,
+
+        /// Don't open the UI, but print what it would show after `--steps` steps
+        #[arg(long)]
+        headless: bool,
+
+        /// Number of steps to take before printing (defaults to running until the end)
+        #[arg(long, requires = "headless")]
+        steps: Option<usize>,
+
+        /// Width of the screen to print, in characters (defaults to fitting the whole grid)
+        #[arg(long, requires = "headless")]
+        width: Option<u16>,
+
+        /// Height of the screen to print, in lines (defaults to fitting the whole grid)
+        #[arg(long, requires = "headless")]
+        height: Option<u16>,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+/// A time budget in seconds, which has to be positive.
+fn parse_budget(s: &str) -> Result<f64, String> {
+    match s.parse::<f64>() {
+        Ok(seconds) if seconds > 0.0 && seconds.is_finite() => Ok(seconds),
+        _ => Err(format!("'{}' is not a positive number of seconds", s)),
+    }
+}
+
+fn day_dir(day: u8) -> String {
+    format!("{}/day{:02}", WORKSPACE_ROOT, day)
+}
+
+fn default_input(day: u8) -> String {
+    format!("{}/input.txt", day_dir(day))
+}
+
+/// Prints a parse error, quoting the offending line of the input.
+fn report_error(day: u8, path: &str, source: &str, err: &ParseError) {
+    eprintln!(
+        "Day {:02}: {}:{}:{}: {}",
+        day, path, err.line, err.column, err.message
+    );
+    if let Some(line) = source.lines().nth(err.line - 1) {
+        let gutter = " ".repeat(err.line.to_string().len());
+        let marker = "^".repeat(err.text.chars().count().max(1));
+        eprintln!("{} |", gutter);
+        eprintln!("{} | {}", err.line, line);
+        eprintln!("{} | {}{}", gutter, " ".repeat(err.column - 1), marker);
+    }
+}
+
+/// Why a day could not be run.
+enum Failure {
+    Read(String, io::Error),
+    Parse {
+        path: String,
+        text: String,
+        err: ParseError,
+    },
+}
+
+/// Reads the input of `day` (from `input` if given) and runs the requested part(s) on it.
+fn run_day(
+    day: u8,
+    input: Option<&str>,
+    part: Option<u8>,
+    variant: Option<&str>,
+) -> Result<Run, Failure> {
+    let path = input.map_or_else(|| default_input(day), str::to_string);
+    let text = match read_text(&path) {
+        Ok(text) => text,
+        Err(err) => return Err(Failure::Read(path, err)),
+    };
+    match days::run(day, &text, part, variant).expect("selection only contains known days") {
+        Ok(run) => Ok(run),
+        Err(err) => Err(Failure::Parse { path, text, err }),
+    }
+}
+
+/// Formats a number of bytes with 4 significant digits and a binary unit, e.g. `1.205 MiB`.
+fn format_bytes(bytes: u64) -> String {
+    let bytes = bytes as f64;
+    let (value, unit) = match bytes {
+        b if b < 1024.0 => return format!("{} B", b),
+        b if b < 1024.0 * 1024.0 => (b / 1024.0, "KiB"),
+        b if b < 1024.0 * 1024.0 * 1024.0 => (b / (1024.0 * 1024.0), "MiB"),
+        b => (b / (1024.0 * 1024.0 * 1024.0), "GiB"),
+    };
+    match value {
+        v if v < 10.0 => format!("{:.3} {}", v, unit),
+        v if v < 100.0 => format!("{:.2} {}", v, unit),
+        v => format!("{:.1} {}", v, unit),
+    }
+}
+
+/// Prints what the parsing and each part of `run` allocated, if that was counted.
+fn print_allocations(run: &Run) {
+    let Some(parse) = run.parse_allocations else {
+        return;
+    };
+    let row = |name: String, allocations: &Allocations| {
+        println!(
+            "  {:<8} {:>12} {:>12} {:>12}",
+            name,
+            allocations.count,
+            format_bytes(allocations.bytes),
+            format_bytes(allocations.peak)
+        );
+    };
+    println!(
+        "  {:<8} {:>12} {:>12} {:>12}",
+        "", "Allocations", "Allocated", "Peak"
+    );
+    row("Parse".to_string(), &parse);
+    for part in &run.parts {
+        if let Some(allocations) = &part.allocations {
+            row(format!("Part {}", part.part), allocations);
+        }
+    }
+}
+
+/// Prints the time every day took, slowest first, and the total.
+fn print_summary(runs: &[(u8, &Run)], jobs: usize, wall_time: Duration) {
+    let total =
+        |run: &Run| run.parse_time + run.parts.iter().map(|part| part.time).sum::<Duration>();
+    let mut runs = runs.to_vec();
+    runs.sort_by_key(|&(day, run)| (std::cmp::Reverse(total(run)), day));
+
+    let part_time = |run: &Run, number: u8| {
+        run.parts
+            .iter()
+            .find(|part| part.part == number)
+            .map_or("-".to_string(), |part| format_duration(part.time))
+    };
+    println!();
+    println!(
+        "{:<6} {:>10} {:>10} {:>10} {:>10}",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+    for (day, run) in &runs {
+        println!(
+            "{:<6} {:>10} {:>10} {:>10} {:>10}",
+            format!("{:02}", day),
+            format_duration(run.parse_time),
+            part_time(run, 1),
+            part_time(run, 2),
+            format_duration(total(run)),
+        );
+    }
+
+    let sum: Duration = runs.iter().map(|(_, run)| total(run)).sum();
+    let threads = match jobs {
+        1 => "1 thread".to_string(),
+        jobs => format!("{} threads", jobs),
+    };
+    println!(
+        "Total: {} across {} days, {} wall time on {}",
+        format_duration(sum),
+        runs.len(),
+        format_duration(wall_time),
+        threads
+    );
+}
+
+/// Checks that `name` is a variant of `day` that solves `part` (if only one part is run).
+fn check_variant(day: u8, part: Option<u8>, name: &str) -> Result<(), String> {
+    let variants = days::variants(day).expect("selection only contains known days");
+    let names: Vec<&str> = variants.iter().map(|(_, name)| *name).collect();
+    match variants.iter().find(|(_, variant)| *variant == name) {
+        None if names.is_empty() => Err(format!("day {} has no variants", day)),
+        None => Err(format!(
+            "day {} has no variant '{}', expected one of: {}",
+            day,
+            name,
+            names.join(", ")
+        )),
+        Some((variant_part, _)) if part.is_some_and(|part| part != *variant_part) => Err(format!(
+            "variant '{}' solves part {}, not part {}",
+            name,
+            variant_part,
+            part.unwrap()
+        )),
+        Some(_) => Ok(()),
+    }
+}
+
+fn run(
+    days: &Selection,
+    part: Option<u8>,
+    input: Option<String>,
+    variant: Option<String>,
+    format: Format,
+    jobs: usize,
+) -> ExitCode {
+    if input.is_some() && days.days().len() != 1 {
+        eprintln!("--input can only be used when running a single day");
+        return ExitCode::FAILURE;
+    }
+    if let Some(name) = &variant {
+        if days.days().len() != 1 {
+            eprintln!("--variant can only be used when running a single day");
+            return ExitCode::FAILURE;
+        }
+        if let Err(err) = check_variant(days.days()[0], part, name) {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let jobs = jobs.min(days.days().len());
+    let start = Instant::now();
+    let results = pool::map(days.days(), jobs, |&day| {
+        run_day(day, input.as_deref(), part, variant.as_deref())
+    });
+    let wall_time = start.elapsed();
+
+    // Report in order of the days, however they finished
+    let mut status = ExitCode::SUCCESS;
+    let mut runs = Vec::new();
+    for (&day, result) in days.days().iter().zip(&results) {
+        match result {
+            Ok(run) => runs.push((day, run)),
+            Err(Failure::Read(path, err)) => {
+                eprintln!("Day {:02}: {}: {}", day, path, err);
+                status = ExitCode::FAILURE;
+            }
+            Err(Failure::Parse { path, text, err }) => {
+                report_error(day, path, text, err);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    match format {
+        Format::Text => {
+            for (day, run) in &runs {
+                println!("Day {:02}", day);
+                for part in &run.parts {
+                    match part.variant {
+                        Some(variant) => {
+                            println!("  Part {} ({}): {}", part.part, variant, part.answer)
+                        }
+                        None => println!("  Part {}: {}", part.part, part.answer),
+                    }
+                }
+                print_allocations(run);
+            }
+            if runs.len() > 1 {
+                print_summary(&runs, jobs, wall_time);
+            }
+        }
+        Format::Json => {
+            let records: Vec<_> = runs
+                .iter()
+                .flat_map(|(day, run)| run.records(*day))
+                .collect();
+            println!(
+                "{}",
+                serde_json::to_string(&records).expect("records serialize")
+            );
+        }
+    }
+    status
+}
+
+/// Runs every input listed in the answers files of `days`, and reports each answer that does not
+/// match the recorded one. Fails if anything did not match or could not be checked.
+fn verify(days: &Selection) -> ExitCode {
+    let mut checked = 0;
+    let mut failures = 0;
+    for &day in days.days() {
+        let path = format!("{}/answers.txt", day_dir(day));
+        let text = match answers::load(&path) {
+            Ok(Some(text)) => text,
+            Ok(None) => {
+                println!("Day {:02}: no recorded answers", day);
+                continue;
+            }
+            Err(err) => {
+                eprintln!("Day {:02}: {}: {}", day, path, err);
+                failures += 1;
+                continue;
+            }
+        };
+        let recorded = match answers::parse(&text) {
+            Ok(recorded) => recorded,
+            Err(err) => {
+                report_error(day, &path, &text, &err);
+                failures += 1;
+                continue;
+            }
+        };
+
+        // Entries without any known answers (e.g. for a freshly created day) have nothing to check
+        for entry in recorded
+            .iter()
+            .filter(|entry| entry.answers.iter().any(Option::is_some))
+        {
+            let input_path = format!("{}/{}", day_dir(day), entry.input);
+            let input = match read_text(&input_path) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("Day {:02}: {}: {}", day, input_path, err);
+                    failures += 1;
+                    continue;
+                }
+            };
+            let run = match days::run(day, &input, None, None)
+                .expect("selection only contains known days")
+            {
+                Ok(run) => run,
+                Err(err) => {
+                    report_error(day, &input_path, &input, &err);
+                    failures += 1;
+                    continue;
+                }
+            };
+
+            let mut ok = true;
+            for part in run.parts {
+                let Some(expected) = entry.expected(part.part) else {
+                    continue;
+                };
+                checked += 1;
+                let answer = part.answer.to_string();
+                if answer != expected {
+                    println!(
+                        "Day {:02} ({}): part {} is {}, expected {}",
+                        day, entry.input, part.part, answer, expected
+                    );
+                    failures += 1;
+                    ok = false;
+                }
+            }
+            if ok {
+                println!("Day {:02} ({}): ok", day, entry.input);
+            }
+        }
+    }
+
+    println!("{} answers checked, {} failures", checked, failures);
+    match failures {
+        0 => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
+
+/// Downloads the inputs of `days` (or takes them from the cache), and saves them as the days'
+/// `input.txt`. An existing `input.txt` is never overwritten.
+fn fetch(days: &Selection) -> ExitCode {
+    let client = match Config::load() {
+        Ok(config) => Client::new(config),
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    for &day in days.days() {
+        let input = match client.input(day) {
+            Ok(input) => input,
+            Err(err @ client::Error::NoSession) => {
+                eprintln!("{}", err);
+                return ExitCode::FAILURE;
+            }
+            Err(err) => {
+                eprintln!("Day {:02}: {}", day, err);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        let path = default_input(day);
+        match std::fs::read_to_string(&path) {
+            Ok(existing) if existing == input => println!("Day {:02}: up to date", day),
+            Ok(_) => println!(
+                "Day {:02}: {} differs from the downloaded input, leaving it alone",
+                day, path
+            ),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => match std::fs::write(&path, input)
+            {
+                Ok(()) => println!("Day {:02}: saved to {}", day, path),
+                Err(err) => {
+                    eprintln!("Day {:02}: {}: {}", day, path, err);
+                    status = ExitCode::FAILURE;
+                }
+            },
+            Err(err) => {
+                eprintln!("Day {:02}: {}: {}", day, path, err);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
+
+/// Runs `part` of `day` on the input at `path`, printing any problems along the way.
+fn solve(day: u8, part: u8, path: &str) -> Option<String> {
+    let text = match read_text(path) {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("Day {:02}: {}: {}", day, path, err);
+            return None;
+        }
+    };
+    match days::run(day, &text, Some(part), None) {
+        None => {
+            eprintln!("day {} is not implemented", day);
+            None
+        }
+        Some(Err(err)) => {
+            report_error(day, path, &text, &err);
+            None
+        }
+        Some(Ok(run)) => match run.parts.into_iter().next()?.answer {
+            Answer::Unsolvable => {
+                eprintln!("Day {:02}: {}: part {} has no solution", day, path, part);
+                None
+            }
+            answer => Some(answer.to_string()),
+        },
+    }
+}
+
+fn describe(outcome: &Outcome) -> String {
+    match outcome {
+        Outcome::Correct => "correct".to_string(),
+        Outcome::Wrong(Hint::TooHigh) => "wrong (too high)".to_string(),
+        Outcome::Wrong(Hint::TooLow) => "wrong (too low)".to_string(),
+        Outcome::Wrong(Hint::None) => "wrong".to_string(),
+        Outcome::Wait(wait) => format!("not checked, wait {}s", wait.as_secs()),
+        Outcome::AlreadySolved => "not checked, the part was already solved".to_string(),
+    }
+}
+
+fn submit(
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    input: Option<String>,
+    wait: bool,
+) -> ExitCode {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => match solve(day, part, &input.unwrap_or_else(|| default_input(day))) {
+            Some(answer) => answer,
+            None => return ExitCode::FAILURE,
+        },
+    };
+    let client = match Config::load() {
+        Ok(config) => Client::new(config),
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("Day {:02} part {}: submitting {}", day, part, answer);
+    loop {
+        let outcome = match client.submit(day, part, &answer) {
+            Ok(Submission::Sent(Outcome::Wait(cooldown)) | Submission::CoolingDown(cooldown))
+                if wait =>
+            {
+                println!(
+                    "  waiting {}s for the server to accept answers",
+                    cooldown.as_secs()
+                );
+                std::thread::sleep(cooldown + Duration::from_secs(1));
+                continue;
+            }
+            Ok(Submission::CoolingDown(cooldown)) => {
+                println!(
+                    "  not sent, the server does not accept answers for another {}s (use --wait)",
+                    cooldown.as_secs()
+                );
+                return ExitCode::FAILURE;
+            }
+            Ok(Submission::Known(outcome)) => {
+                println!(
+                    "  not sent, known from earlier submissions: {}",
+                    describe(&outcome)
+                );
+                outcome
+            }
+            Ok(Submission::Sent(outcome)) => {
+                println!("  {}", describe(&outcome));
+                outcome
+            }
+            Err(err) => {
+                eprintln!("{}", err);
+                return ExitCode::FAILURE;
+            }
+        };
+        return match outcome {
+            Outcome::Correct | Outcome::AlreadySolved => ExitCode::SUCCESS,
+            _ => ExitCode::FAILURE,
+        };
+    }
+}
+
+/// Draws `day` from its input (or the one at `input`), and writes the picture into `out`.
+fn render(day: u8, input: Option<String>, out: &Path, scale: usize, frames: bool) -> ExitCode {
+    if !renders::RENDERS.contains(&day) {
+        let days: Vec<String> = renders::RENDERS.iter().map(u8::to_string).collect();
+        eprintln!(
+            "day {} can't be drawn, only days {} can",
+            day,
+            days.join(", ")
+        );
+        return ExitCode::FAILURE;
+    }
+
+    let path = input.unwrap_or_else(|| default_input(day));
+    let text = match read_text(&path) {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("Day {:02}: {}: {}", day, path, err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let picture = match renders::render(day, &text).expect("checked that the day can be drawn") {
+        Ok(picture) => picture,
+        Err(err) => {
+            report_error(day, &path, &text, &err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let written = match frames {
+        true => {
+            let dir = out.join(format!("day{:02}", day));
+            picture
+                .write_frames(&dir, scale)
+                .map(|paths| format!("{} frame(s) to {}", paths.len(), dir.display()))
+        }
+        false => {
+            let file = out.join(format!("day{:02}.{}", day, picture.extension()));
+            std::fs::create_dir_all(out)
+                .and_then(|()| picture.write(&file, scale))
+                .map(|()| file.display().to_string())
+        }
+    };
+    match written {
+        Ok(written) => {
+            println!("Wrote {}", written);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Day {:02}: {}: {}", day, out.display(), err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Where `aoc visualise --headless` stops, and the size of the screen it prints.
+struct Headless {
+    steps: Option<usize>,
+    width: Option<u16>,
+    height: Option<u16>,
+}
+
+/// Steps through `day` with its input (or the one at `input`), in the terminal or, if `headless`
+/// is given, by printing the screen at the end.
+fn visualise(day: u8, input: Option<String>, headless: Option<Headless>) -> ExitCode {
+    if !visualisers::VISUALISERS.contains(&day) {
+        let days: Vec<String> = visualisers::VISUALISERS.iter().map(u8::to_string).collect();
+        eprintln!(
+            "day {} can't be stepped through, only days {} can",
+            day,
+            days.join(", ")
+        );
+        return ExitCode::FAILURE;
+    }
+
+    let path = input.unwrap_or_else(|| default_input(day));
+    let text = match read_text(&path) {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("Day {:02}: {}: {}", day, path, err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let title = format!("Day {:02}", day);
+    let shown = visualisers::visualise(day, &text, |simulation| match headless {
+        Some(headless) => {
+            // Room for the whole grid inside the border, and the two lines under it
+            let frame = simulation.frame();
+            let width = headless.width.unwrap_or((frame.width() + 2).max(80) as u16);
+            let height = headless.height.unwrap_or(frame.height() as u16 + 4);
+            let mut viewer = visualise::Viewer::new(&title, simulation);
+            viewer.advance(headless.steps.unwrap_or(usize::MAX));
+            println!("{}", viewer.snapshot(width, height));
+            Ok(())
+        }
+        None => visualise::run(&title, simulation),
+    });
+    match shown.expect("checked that the day can be stepped through") {
+        Ok(Ok(())) => ExitCode::SUCCESS,
+        Ok(Err(err)) => {
+            eprintln!("Day {:02}: {}", day, err);
+            ExitCode::FAILURE
+        }
+        Err(err) => {
+            report_error(day, &path, &text, &err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Sends the logs selected by `-v` or `--log` to stderr, where they don't mix with the answers.
+fn init_logging(verbose: u8, log: Option<Targets>) {
+    let filter = log.unwrap_or_else(|| {
+        Targets::new().with_default(match verbose {
+            0 => LevelFilter::WARN,
+            1 => LevelFilter::INFO,
+            2 => LevelFilter::DEBUG,
+            _ => LevelFilter::TRACE,
+        })
+    });
+    // Closing a span logs how long it took, i.e. the time of each day, parse and part
+    let layer = tracing_subscriber::fmt::layer()
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .with_span_events(FmtSpan::CLOSE);
+    tracing_subscriber::registry()
+        .with(layer.with_filter(filter))
+        .init();
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_logging(cli.verbose, cli.log);
+
+    match cli.command {
+        Command::Run {
+            days,
+            part,
+            input,
+            format,
+            jobs,
+            sequential,
+            variant,
+        } => {
+            let jobs = match (sequential, jobs) {
+                (true, _) => 1,
+                (false, Some(jobs)) => jobs as usize,
+                (false, None) => pool::default_jobs(),
+            };
+            run(&days, part, input, variant, format, jobs)
+        }
+        Command::Fetch { days } => fetch(&days),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            input,
+            wait,
+        } => submit(day, part, answer, input, wait),
+        Command::Verify { days } => verify(&days),
+        Command::Compare {
+            day,
+            dir,
+            part,
+            budget,
+        } => compare::compare(day, &dir, part, Duration::from_secs_f64(budget)),
+        Command::Generate { day, seed, size } => match days::generate(day, seed, size) {
+            Some(input) => {
+                print!("{}", input);
+                ExitCode::SUCCESS
+            }
+            None => {
+                eprintln!("day {} is not implemented", day);
+                ExitCode::FAILURE
+            }
+        },
+        Command::Render {
+            day,
+            input,
+            out,
+            scale,
+            frames,
+        } => render(day, input, &out, scale as usize, frames),
+        Command::Visualise {
+            day,
+            input,
+            headless,
+            steps,
+            width,
+            height,
+        } => visualise(
+            day,
+            input,
+            headless.then_some(Headless {
+                steps,
+                width,
+                height,
+            }),
+        ),
+    }
+}
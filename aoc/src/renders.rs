@@ -0,0 +1,32 @@
+use common::{ParseError, Solution};
+use render::{Picture, Render};
+
+/// Generates the list of the days that can be drawn, and a `render` function that dispatches to
+/// their [`Render`] implementations. Unlike `days!`, a day only shows up here once it has a
+/// `src/render.rs`.
+macro_rules! renders {
+    ($($day:literal => $solution:path),* $(,)?) => {
+        /// All days that can be drawn, in ascending order.
+        pub const RENDERS: &[u8] = &[$($day),*];
+
+        /// Parses the puzzle input `input` of `day`, and draws it.
+        ///
+        /// Returns `None` if `day` can't be drawn.
+        pub fn render(day: u8, input: &str) -> Option<Result<Picture, ParseError>> {
+            match day {
+                $($day => Some(
+                    <$solution as Solution>::parse(input)
+                        .map(|input| <$solution as Render>::render(&input)),
+                ),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+renders! {
+    10 => day10::Day10,
+    14 => day14::Day14,
+    16 => day16::Day16,
+    17 => day17::Day17,
+}
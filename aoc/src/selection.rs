@@ -0,0 +1,54 @@
+use std::str::FromStr;
+
+use crate::days::DAYS;
+
+/// A set of days picked on the command line, e.g. `17`, `3-7`, `1,4,10-12` or `all`.
+#[derive(Debug, Clone)]
+pub struct Selection {
+    days: Vec<u8>,
+}
+
+impl Selection {
+    pub fn days(&self) -> &[u8] {
+        &self.days
+    }
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Selection {
+                days: DAYS.to_vec(),
+            });
+        }
+
+        let mut days = Vec::new();
+        for group in s.split(',') {
+            let (start, end) = match group.split_once('-') {
+                Some((start, end)) => (parse_day(start)?, parse_day(end)?),
+                None => (parse_day(group)?, parse_day(group)?),
+            };
+            if start > end {
+                return Err(format!("empty day range '{}'", group));
+            }
+            days.extend(start..=end);
+        }
+
+        days.sort_unstable();
+        days.dedup();
+        Ok(Selection { days })
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    let day: u8 = s
+        .trim()
+        .parse()
+        .map_err(|_| format!("'{}' is not a day number", s))?;
+    match DAYS.contains(&day) {
+        true => Ok(day),
+        false => Err(format!("day {} is not implemented", day)),
+    }
+}
@@ -0,0 +1,35 @@
+use common::{ParseError, Solution};
+use visualise::{Simulation, Visualise};
+
+/// Generates the list of the days that can be stepped through, and a `visualise` function that
+/// dispatches to their [`Visualise`] implementations. Like `renders!`, a day only shows up here
+/// once it has a `src/visualise.rs`.
+macro_rules! visualisers {
+    ($($day:literal => $solution:path),* $(,)?) => {
+        /// All days that can be stepped through, in ascending order.
+        pub const VISUALISERS: &[u8] = &[$($day),*];
+
+        /// Parses the puzzle input `input` of `day`, and hands its simulation to `show`.
+        ///
+        /// Returns `None` if `day` can't be stepped through.
+        pub fn visualise<R>(
+            day: u8,
+            input: &str,
+            show: impl FnOnce(Box<dyn Simulation + '_>) -> R,
+        ) -> Option<Result<R, ParseError>> {
+            match day {
+                $($day => Some(
+                    <$solution as Solution>::parse(input)
+                        .map(|input| show(<$solution as Visualise>::simulation(&input))),
+                ),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+visualisers! {
+    14 => day14::Day14,
+    16 => day16::Day16,
+    17 => day17::Day17,
+}
@@ -0,0 +1,75 @@
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::Error;
+
+/// Where to find the server, and how to authenticate with it.
+///
+/// Read from `<config dir>/aoc/config.toml` (e.g. `~/.config/aoc/config.toml`), with every setting
+/// overridable through an environment variable:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."        # AOC_SESSION
+/// base_url = "https://adventofcode.com"  # AOC_BASE_URL
+/// cache_dir = "/home/me/.cache/aoc"      # AOC_CACHE_DIR
+/// ```
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Value of the `session` cookie of a logged in browser.
+    pub session: Option<String>,
+    /// Base URL of the server, without a trailing slash.
+    pub base_url: String,
+    /// Downloaded inputs are stored under `<cache_dir>/<year>/`.
+    pub cache_dir: PathBuf,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    session: Option<String>,
+    base_url: Option<String>,
+    cache_dir: Option<PathBuf>,
+}
+
+impl Config {
+    pub const DEFAULT_BASE_URL: &'static str = "https://adventofcode.com";
+
+    /// Location of the config file, if the platform has a config directory.
+    pub fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("aoc").join("config.toml"))
+    }
+
+    /// Loads the config file (if there is one) and applies the environment overrides.
+    pub fn load() -> Result<Self, Error> {
+        let file = match Config::path() {
+            Some(path) if path.exists() => {
+                let text =
+                    std::fs::read_to_string(&path).map_err(|err| Error::Io(path.clone(), err))?;
+                toml::from_str(&text).map_err(|err| Error::Config(path, err.to_string()))?
+            }
+            _ => ConfigFile::default(),
+        };
+
+        let env = |name| {
+            std::env::var(name)
+                .ok()
+                .filter(|value: &String| !value.is_empty())
+        };
+        let session = env("AOC_SESSION").or(file.session);
+        let base_url = env("AOC_BASE_URL")
+            .or(file.base_url)
+            .unwrap_or_else(|| Config::DEFAULT_BASE_URL.to_string());
+        let cache_dir = env("AOC_CACHE_DIR")
+            .map(PathBuf::from)
+            .or(file.cache_dir)
+            .or_else(|| dirs::cache_dir().map(|dir| dir.join("aoc")))
+            .unwrap_or_else(|| PathBuf::from(".aoc-cache"));
+
+        Ok(Config {
+            session: session.map(|session| session.trim().to_string()),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            cache_dir,
+        })
+    }
+}
@@ -0,0 +1,88 @@
+use std::path::PathBuf;
+use std::{fmt, io};
+
+use crate::Config;
+
+/// Anything that can go wrong while talking to the server.
+#[derive(Debug)]
+pub enum Error {
+    /// There is no session token to authenticate with.
+    NoSession,
+    /// The config file exists, but could not be read.
+    Config(PathBuf, String),
+    /// The request could not be made, or the response could not be read.
+    Request(String, ureq::Error),
+    /// The server responded with something other than 200 OK.
+    Http {
+        url: String,
+        status: u16,
+        body: String,
+    },
+    /// The server responded with a message we don't understand.
+    UnexpectedResponse {
+        url: String,
+        text: String,
+    },
+    /// The answer can't be submitted, as it's empty or contains whitespace.
+    Answer(String),
+    /// A line of a day's submission history (`dayNN.submissions`) is not a valid entry.
+    History {
+        path: PathBuf,
+        /// 1-based line number.
+        line: usize,
+        text: String,
+    },
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NoSession => {
+                write!(f, "no session token: set AOC_SESSION")?;
+                match Config::path() {
+                    Some(path) => write!(f, " or `session` in {}", path.display()),
+                    None => Ok(()),
+                }
+            }
+            Error::Config(path, err) => write!(f, "{}: {}", path.display(), err),
+            Error::Request(url, err) => write!(f, "{}: {}", url, err),
+            Error::Http { url, status, body } => {
+                let reason = match status {
+                    400 | 401 => "the session token was not accepted",
+                    404 => "not found, the puzzle may not be unlocked yet",
+                    500..=599 => "server error",
+                    _ => "unexpected response",
+                };
+                write!(f, "{}: HTTP {}, {}", url, status, reason)?;
+                // The body is usually a one-line explanation, but don't dump entire pages
+                match body.lines().map(str::trim).find(|line| !line.is_empty()) {
+                    Some(line) if line.len() <= 200 => write!(f, " ({})", line),
+                    _ => Ok(()),
+                }
+            }
+            Error::UnexpectedResponse { url, text } => {
+                write!(f, "{}: unexpected response '{}'", url, text)
+            }
+            Error::Answer(answer) => write!(f, "'{}' is not a valid answer", answer),
+            Error::History { path, line, text } => write!(
+                f,
+                "{}: line {}: invalid submission history entry '{}'",
+                path.display(),
+                line,
+                text
+            ),
+            Error::Io(path, err) => write!(f, "{}: {}", path.display(), err),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Request(_, err) => Some(err),
+            Error::Io(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
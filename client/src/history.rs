@@ -0,0 +1,156 @@
+//! Everything we've learned from submitting answers for a day, stored next to the cached input
+//! as `dayNN.submissions`:
+//!
+//! ```text
+//! 1 12345 too-high
+//! 1 12000 correct
+//! 2 999 wrong
+//! wait-until 1702023123
+//! ```
+//!
+//! `wait-until` is the (unix) time until which the server does not accept answers for this day.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{Error, Hint, Outcome};
+
+#[derive(Debug, Default)]
+pub struct History {
+    path: PathBuf,
+    /// Every answer that got a verdict, as `(part, answer, outcome)`.
+    answers: Vec<(u8, String, Outcome)>,
+    wait_until: u64,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn outcome_name(outcome: &Outcome) -> Option<&'static str> {
+    match outcome {
+        Outcome::Correct => Some("correct"),
+        Outcome::Wrong(Hint::TooHigh) => Some("too-high"),
+        Outcome::Wrong(Hint::TooLow) => Some("too-low"),
+        Outcome::Wrong(Hint::None) => Some("wrong"),
+        // Neither says anything about the answer itself
+        Outcome::Wait(_) | Outcome::AlreadySolved => None,
+    }
+}
+
+fn parse_outcome(name: &str) -> Option<Outcome> {
+    match name {
+        "correct" => Some(Outcome::Correct),
+        "too-high" => Some(Outcome::Wrong(Hint::TooHigh)),
+        "too-low" => Some(Outcome::Wrong(Hint::TooLow)),
+        "wrong" => Some(Outcome::Wrong(Hint::None)),
+        _ => None,
+    }
+}
+
+impl History {
+    /// Loads the history stored at `path`. A missing file is an empty history.
+    pub fn load(path: PathBuf) -> Result<Self, Error> {
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(Error::Io(path, err)),
+        };
+
+        let mut history = History {
+            path,
+            ..Default::default()
+        };
+        for (idx, line) in text.lines().enumerate() {
+            let invalid = || Error::History {
+                path: history.path.clone(),
+                line: idx + 1,
+                text: line.to_string(),
+            };
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields[..] {
+                [] => {}
+                ["wait-until", time] => history.wait_until = time.parse().map_err(|_| invalid())?,
+                [part, answer, outcome] => {
+                    let part = part.parse().map_err(|_| invalid())?;
+                    let outcome = parse_outcome(outcome).ok_or_else(invalid)?;
+                    history.answers.push((part, answer.to_string(), outcome));
+                }
+                _ => return Err(invalid()),
+            }
+        }
+        Ok(history)
+    }
+
+    fn save(&self) -> Result<(), Error> {
+        let mut text = String::new();
+        for (part, answer, outcome) in &self.answers {
+            let name = outcome_name(outcome).expect("only verdicts are recorded");
+            text += &format!("{} {} {}\n", part, answer, name);
+        }
+        if self.wait_until > now() {
+            text += &format!("wait-until {}\n", self.wait_until);
+        }
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|err| Error::Io(dir.to_path_buf(), err))?;
+        }
+        fs::write(&self.path, text).map_err(|err| Error::Io(self.path.clone(), err))
+    }
+
+    /// How long we still have to wait before the server accepts another answer.
+    pub fn cooldown(&self) -> Option<Duration> {
+        self.wait_until
+            .checked_sub(now())
+            .filter(|&secs| secs > 0)
+            .map(Duration::from_secs)
+    }
+
+    /// What we already know about `answer`, without asking the server: the verdict it got before,
+    /// that the part was already solved with another answer, or a hint that follows from earlier
+    /// verdicts (e.g. it is higher than an answer that was too high).
+    pub fn known(&self, part: u8, answer: &str) -> Option<Outcome> {
+        let answers = self.answers.iter().filter(|(p, _, _)| *p == part);
+        if let Some((_, _, outcome)) = answers.clone().find(|(_, a, _)| a == answer) {
+            return Some(outcome.clone());
+        }
+        if answers
+            .clone()
+            .any(|(_, _, outcome)| *outcome == Outcome::Correct)
+        {
+            return Some(Outcome::AlreadySolved);
+        }
+
+        let answer: i128 = answer.parse().ok()?;
+        answers
+            .filter_map(|(_, a, outcome)| Some((a.parse::<i128>().ok()?, outcome)))
+            .find_map(|(a, outcome)| match outcome {
+                Outcome::Wrong(Hint::TooHigh) if answer >= a => Some(Outcome::Wrong(Hint::TooHigh)),
+                Outcome::Wrong(Hint::TooLow) if answer <= a => Some(Outcome::Wrong(Hint::TooLow)),
+                _ => None,
+            })
+    }
+
+    /// Records the server's response to submitting `answer`.
+    pub fn record(
+        &mut self,
+        part: u8,
+        answer: &str,
+        outcome: &Outcome,
+        cooldown: Option<Duration>,
+    ) -> Result<(), Error> {
+        if outcome_name(outcome).is_some() {
+            self.answers
+                .push((part, answer.to_string(), outcome.clone()));
+        }
+        if let Some(cooldown) = cooldown {
+            self.wait_until = now() + cooldown.as_secs();
+        }
+        self.save()
+    }
+}
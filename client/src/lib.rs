@@ -0,0 +1,108 @@
+//! Talks to the Advent of Code website: downloading puzzle inputs, which are cached locally so
+//! that each one is only ever downloaded once, and submitting answers.
+
+mod config;
+mod error;
+mod history;
+mod submit;
+
+use std::fs;
+use std::path::PathBuf;
+
+pub use config::Config;
+pub use error::Error;
+pub use submit::{Hint, Outcome, Submission};
+
+/// The event all days belong to.
+pub const YEAR: u16 = 2023;
+
+/// Identifies this tool to the server, as the Advent of Code maintainers ask automated tools to do.
+const USER_AGENT: &str = concat!("AdventOfCode2023-runner/", env!("CARGO_PKG_VERSION"));
+
+pub struct Client {
+    config: Config,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        let agent = ureq::Agent::config_builder()
+            // We want to read the body of error responses, to report what went wrong
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+        Client { config, agent }
+    }
+
+    /// Where the input for `day` is cached.
+    pub fn cache_path(&self, day: u8) -> PathBuf {
+        self.config
+            .cache_dir
+            .join(YEAR.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    /// The puzzle input for `day`, downloaded if it has not been cached yet.
+    pub fn input(&self, day: u8) -> Result<String, Error> {
+        let path = self.cache_path(day);
+        if path.exists() {
+            return fs::read_to_string(&path).map_err(|err| Error::Io(path, err));
+        }
+
+        let url = format!("{}/{}/day/{}/input", self.config.base_url, YEAR, day);
+        let input = self.get(&url)?;
+
+        // Write to a temporary file first, so that an interrupted write is never mistaken for a
+        // cached input
+        let dir = path.parent().expect("cache path has a parent");
+        fs::create_dir_all(dir).map_err(|err| Error::Io(dir.to_path_buf(), err))?;
+        let partial = path.with_extension("part");
+        fs::write(&partial, &input).map_err(|err| Error::Io(partial.clone(), err))?;
+        fs::rename(&partial, &path).map_err(|err| Error::Io(path, err))?;
+        Ok(input)
+    }
+
+    fn session(&self) -> Result<&str, Error> {
+        self.config.session.as_deref().ok_or(Error::NoSession)
+    }
+
+    fn get(&self, url: &str) -> Result<String, Error> {
+        let session = self.session()?;
+        let response = self
+            .agent
+            .get(url)
+            .header("Cookie", format!("session={}", session))
+            .call()
+            .map_err(|err| Error::Request(url.to_string(), err))?;
+        read_body(url, response)
+    }
+
+    fn post_form(&self, url: &str, form: &[(&str, &str)]) -> Result<String, Error> {
+        let session = self.session()?;
+        let response = self
+            .agent
+            .post(url)
+            .header("Cookie", format!("session={}", session))
+            .send_form(form.iter().copied())
+            .map_err(|err| Error::Request(url.to_string(), err))?;
+        read_body(url, response)
+    }
+}
+
+/// Reads the body of a successful response, or turns an unsuccessful one into an error.
+fn read_body(url: &str, mut response: ureq::http::Response<ureq::Body>) -> Result<String, Error> {
+    let status = response.status().as_u16();
+    let body = response
+        .body_mut()
+        .read_to_string()
+        .map_err(|err| Error::Request(url.to_string(), err))?;
+    match status {
+        200 => Ok(body),
+        _ => Err(Error::Http {
+            url: url.to_string(),
+            status,
+            body,
+        }),
+    }
+}
@@ -0,0 +1,160 @@
+//! Submitting answers, without ever sending the same wrong answer twice or ignoring a cooldown.
+
+use std::time::Duration;
+
+use crate::history::History;
+use crate::{Client, Error, YEAR};
+
+/// What the server said about an answer that was too high or too low.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+    None,
+}
+
+/// The server's response to an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong(Hint),
+    /// An answer was submitted too recently; this one was not checked.
+    Wait(Duration),
+    /// The part was already solved, so the answer was not checked.
+    AlreadySolved,
+}
+
+/// What happened to a submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submission {
+    /// The answer was sent, and this is what the server said.
+    Sent(Outcome),
+    /// The answer was not sent, because the outcome already follows from earlier submissions.
+    Known(Outcome),
+    /// The answer was not sent, because the server won't accept answers for this long.
+    CoolingDown(Duration),
+}
+
+impl Client {
+    /// Submits `answer` for `part` of `day`, unless earlier submissions already tell what the
+    /// outcome would be, or we're still supposed to wait.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Submission, Error> {
+        if answer.is_empty() || answer.contains(char::is_whitespace) {
+            return Err(Error::Answer(answer.to_string()));
+        }
+
+        let mut history = History::load(self.history_path(day))?;
+        if let Some(outcome) = history.known(part, answer) {
+            return Ok(Submission::Known(outcome));
+        }
+        if let Some(wait) = history.cooldown() {
+            return Ok(Submission::CoolingDown(wait));
+        }
+
+        let url = format!("{}/{}/day/{}/answer", self.config.base_url, YEAR, day);
+        let body = self.post_form(&url, &[("level", &part.to_string()), ("answer", answer)])?;
+        let text = article_text(&body);
+        let (outcome, cooldown) =
+            parse_response(&text).ok_or_else(|| Error::UnexpectedResponse {
+                url: url.clone(),
+                text: text.clone(),
+            })?;
+
+        history.record(part, answer, &outcome, cooldown)?;
+        Ok(Submission::Sent(outcome))
+    }
+
+    fn history_path(&self, day: u8) -> std::path::PathBuf {
+        self.cache_path(day).with_extension("submissions")
+    }
+}
+
+/// The text of the `<article>` holding the response message, with the markup stripped.
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Recognises the response message, returning the outcome and how long to wait before the next
+/// answer will be accepted.
+fn parse_response(text: &str) -> Option<(Outcome, Option<Duration>)> {
+    let text = text.to_lowercase();
+    if text.contains("that's the right answer") {
+        return Some((Outcome::Correct, None));
+    }
+    if text.contains("you gave an answer too recently") {
+        // "You have 4m 32s left to wait."
+        let wait = between(&text, "you have ", " left to wait").and_then(parse_duration)?;
+        return Some((Outcome::Wait(wait), Some(wait)));
+    }
+    if text.contains("don't seem to be solving the right level") {
+        return Some((Outcome::AlreadySolved, None));
+    }
+    if text.contains("that's not the right answer") {
+        let hint = match (text.contains("too high"), text.contains("too low")) {
+            (true, false) => Hint::TooHigh,
+            (false, true) => Hint::TooLow,
+            _ => Hint::None,
+        };
+        // "Please wait one minute before trying again."
+        let cooldown =
+            between(&text, "please wait ", " before trying again").and_then(parse_duration);
+        return Some((Outcome::Wrong(hint), cooldown));
+    }
+    None
+}
+
+/// The text between `end` and the closest `start` before it.
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let before = &text[..text.find(end)?];
+    Some(&before[before.rfind(start)? + start.len()..])
+}
+
+/// Parses durations as the server phrases them: `4m 32s`, `one minute` or `5 minutes`.
+fn parse_duration(s: &str) -> Option<Duration> {
+    let words: Vec<&str> = s.split_whitespace().collect();
+    if let [count, unit] = words[..] {
+        let count = match count {
+            "one" | "a" => Some(1),
+            _ => count.parse::<u64>().ok(),
+        };
+        let secs = match unit.trim_end_matches('s') {
+            "second" => Some(1),
+            "minute" => Some(60),
+            "hour" => Some(3600),
+            _ => None,
+        };
+        if let (Some(count), Some(secs)) = (count, secs) {
+            return Some(Duration::from_secs(count * secs));
+        }
+    }
+
+    words
+        .iter()
+        .map(|word| {
+            let unit = word.chars().last()?;
+            let count: u64 = word[..word.len() - unit.len_utf8()].parse().ok()?;
+            match unit {
+                'h' => Some(count * 3600),
+                'm' => Some(count * 60),
+                's' => Some(count),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
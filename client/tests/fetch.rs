@@ -0,0 +1,65 @@
+mod mock;
+
+use client::{Client, Error};
+use mock::MockServer;
+
+#[test]
+fn downloads_and_caches_input() {
+    let server = MockServer::start(vec![(200, "1abc2\npqr3stu8vwx\n")]);
+    let client = Client::new(server.config("downloads_and_caches_input"));
+
+    assert_eq!(client.input(1).unwrap(), "1abc2\npqr3stu8vwx\n");
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].url, "/2023/day/1/input");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=s3cr3t"));
+
+    // The second time around it comes from the cache, even with a fresh client
+    let client = Client::new(server.config_without_reset("downloads_and_caches_input"));
+    assert_eq!(client.input(1).unwrap(), "1abc2\npqr3stu8vwx\n");
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn reports_http_errors() {
+    let server = MockServer::start(vec![
+        (
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+        ),
+        (
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks!",
+        ),
+    ]);
+    let client = Client::new(server.config("reports_http_errors"));
+
+    let err = client.input(3).unwrap_err();
+    assert!(matches!(err, Error::Http { status: 400, .. }), "{:?}", err);
+    assert!(
+        err.to_string().contains("session token was not accepted"),
+        "{}",
+        err
+    );
+
+    let err = client.input(25).unwrap_err();
+    assert!(matches!(err, Error::Http { status: 404, .. }), "{:?}", err);
+    assert!(err.to_string().contains("/2023/day/25/input"), "{}", err);
+
+    // Nothing was cached, so asking again goes back to the server
+    assert!(!client.cache_path(3).exists());
+    assert!(client.input(3).is_err());
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn needs_a_session() {
+    let server = MockServer::start(vec![]);
+    let mut config = server.config("needs_a_session");
+    config.session = None;
+    let client = Client::new(config);
+
+    assert!(matches!(client.input(1), Err(Error::NoSession)));
+    assert!(server.requests().is_empty());
+}
@@ -0,0 +1,92 @@
+//! A stand-in for the Advent of Code server, answering requests with canned responses.
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use client::Config;
+
+/// A request as seen by the server.
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct Request {
+    pub method: String,
+    pub url: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+pub struct MockServer {
+    pub base_url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockServer {
+    /// Starts a server that answers the n-th request with the n-th `(status, body)` response,
+    /// and with a 500 once it runs out.
+    pub fn start(responses: Vec<(u16, &str)>) -> Self {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let port = server.server_addr().to_ip().unwrap().port();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let responses: Vec<(u16, String)> = responses
+            .into_iter()
+            .map(|(status, body)| (status, body.to_string()))
+            .collect();
+        let seen = Arc::clone(&requests);
+        thread::spawn(move || {
+            let mut responses = responses.into_iter();
+            for mut request in server.incoming_requests() {
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).unwrap();
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.equiv("Cookie"))
+                    .map(|header| header.value.to_string());
+                seen.lock().unwrap().push(Request {
+                    method: request.method().to_string(),
+                    url: request.url().to_string(),
+                    cookie,
+                    body,
+                });
+
+                let (status, body) = responses
+                    .next()
+                    .unwrap_or((500, "no more responses".to_string()));
+                let response = tiny_http::Response::from_string(body).with_status_code(status);
+                request.respond(response).unwrap();
+            }
+        });
+
+        MockServer {
+            base_url: format!("http://127.0.0.1:{}", port),
+            requests,
+        }
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// A config pointing at this server, with a fresh cache directory named after `test`.
+    pub fn config(&self, test: &str) -> Config {
+        let _ = std::fs::remove_dir_all(cache_dir(test));
+        self.config_without_reset(test)
+    }
+
+    /// Like [`MockServer::config`], but keeps whatever is already in the cache directory.
+    #[allow(dead_code)]
+    pub fn config_without_reset(&self, test: &str) -> Config {
+        Config {
+            session: Some("s3cr3t".to_string()),
+            base_url: self.base_url.clone(),
+            cache_dir: cache_dir(test),
+        }
+    }
+}
+
+/// A cache directory private to `test`.
+pub fn cache_dir(test: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("aoc-client-{}-{}", std::process::id(), test))
+}
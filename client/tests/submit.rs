@@ -0,0 +1,146 @@
+mod mock;
+
+use std::time::Duration;
+
+use client::{Client, Error, Hint, Outcome, Submission};
+use mock::MockServer;
+
+/// Wraps a message the way the server does.
+fn page(message: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+        message
+    )
+}
+
+const CORRECT: &str = "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to restoring snow operations. <a href=\"/2023/day/5#part2\">[Continue to Part Two]</a>";
+const TOO_HIGH: &str = "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. <a href=\"/2023/day/5\">[Return to Day 5]</a>";
+const TOO_LOW: &str = "That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again. <a href=\"/2023/day/5\">[Return to Day 5]</a>";
+const TOO_RECENT: &str = "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait. <a href=\"/2023/day/5\">[Return to Day 5]</a>";
+const SOLVED: &str = "You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2023/day/5\">[Return to Day 5]</a>";
+
+#[test]
+fn submits_and_recognises_the_right_answer() {
+    let server = MockServer::start(vec![(200, &page(CORRECT))]);
+    let client = Client::new(server.config("submits_and_recognises_the_right_answer"));
+
+    assert_eq!(
+        client.submit(5, 1, "486613012").unwrap(),
+        Submission::Sent(Outcome::Correct)
+    );
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].url, "/2023/day/5/answer");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=s3cr3t"));
+    assert_eq!(requests[0].body, "level=1&answer=486613012");
+
+    // Once solved, nothing is sent for that part anymore
+    assert_eq!(
+        client.submit(5, 1, "486613012").unwrap(),
+        Submission::Known(Outcome::Correct)
+    );
+    assert_eq!(
+        client.submit(5, 1, "12").unwrap(),
+        Submission::Known(Outcome::AlreadySolved)
+    );
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn never_resends_wrong_answers_and_respects_the_cooldown() {
+    let server = MockServer::start(vec![(200, &page(TOO_HIGH))]);
+    let client = Client::new(server.config("never_resends_wrong_answers"));
+
+    assert_eq!(
+        client.submit(5, 2, "1000").unwrap(),
+        Submission::Sent(Outcome::Wrong(Hint::TooHigh))
+    );
+
+    // The same answer, or anything higher, is known to be too high
+    assert_eq!(
+        client.submit(5, 2, "1000").unwrap(),
+        Submission::Known(Outcome::Wrong(Hint::TooHigh))
+    );
+    assert_eq!(
+        client.submit(5, 2, "2000").unwrap(),
+        Submission::Known(Outcome::Wrong(Hint::TooHigh))
+    );
+
+    // A new answer has to wait for the cooldown
+    match client.submit(5, 2, "999").unwrap() {
+        Submission::CoolingDown(wait) => assert!(wait <= Duration::from_secs(60), "{:?}", wait),
+        other => panic!("expected a cooldown, got {:?}", other),
+    }
+    assert_eq!(server.requests().len(), 1);
+
+    // The history is kept on disk, so a new client knows it too
+    let client = Client::new(server.config_without_reset("never_resends_wrong_answers"));
+    assert_eq!(
+        client.submit(5, 2, "1500").unwrap(),
+        Submission::Known(Outcome::Wrong(Hint::TooHigh))
+    );
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn recognises_the_other_responses() {
+    let server = MockServer::start(vec![
+        (200, &page(TOO_LOW)),
+        (200, &page(SOLVED)),
+        (200, &page(TOO_RECENT)),
+    ]);
+    let client = Client::new(server.config("recognises_the_other_responses"));
+
+    assert_eq!(
+        client.submit(1, 1, "5").unwrap(),
+        Submission::Sent(Outcome::Wrong(Hint::TooLow))
+    );
+    // Each day has its own history, and with it its own cooldown
+    assert_eq!(
+        client.submit(2, 1, "7").unwrap(),
+        Submission::Sent(Outcome::AlreadySolved)
+    );
+    assert_eq!(
+        client.submit(3, 2, "9").unwrap(),
+        Submission::Sent(Outcome::Wait(Duration::from_secs(4 * 60 + 32)))
+    );
+    assert!(matches!(
+        client.submit(3, 2, "9").unwrap(),
+        Submission::CoolingDown(_)
+    ));
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn rejects_unknown_responses_and_invalid_answers() {
+    let server = MockServer::start(vec![(200, &page("Something else entirely."))]);
+    let client = Client::new(server.config("rejects_unknown_responses"));
+
+    let err = client.submit(4, 1, "10").unwrap_err();
+    assert!(
+        err.to_string().contains("Something else entirely."),
+        "{}",
+        err
+    );
+    assert!(client.submit(4, 1, "").is_err());
+    assert!(client.submit(4, 1, "1 2").is_err());
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn reports_invalid_history_entries() {
+    let server = MockServer::start(vec![]);
+    let client = Client::new(server.config("reports_invalid_history_entries"));
+    let history = client.cache_path(6).with_extension("submissions");
+    std::fs::create_dir_all(history.parent().unwrap()).unwrap();
+    std::fs::write(&history, "1 12 too-low\n1 oops\n").unwrap();
+
+    match client.submit(6, 1, "20").unwrap_err() {
+        Error::History { path, line, text } => {
+            assert_eq!((path, line, text.as_str()), (history, 2, "1 oops"))
+        }
+        other => panic!("expected a history error, got {:?}", other),
+    }
+    assert_eq!(server.requests().len(), 0);
+}
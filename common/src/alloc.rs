@@ -0,0 +1,122 @@
+//! Counting how much a piece of code allocates.
+//!
+//! [`Counting`] wraps the system allocator and keeps a tally per thread, so days that run at the
+//! same time don't count each other's allocations. It only counts once a binary installs it:
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOCATOR: common::alloc::Counting = common::alloc::Counting;
+//! ```
+//!
+//! after which [`measure`] reports what a closure allocated. Memory freed on another thread than
+//! the one that allocated it lowers that thread's live bytes instead, which the solutions never do.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::Serialize;
+
+/// The system allocator, counting every allocation made through it.
+pub struct Counting;
+
+/// What a measured piece of code allocated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Allocations {
+    /// Number of allocations, counting every reallocation as one too.
+    pub count: u64,
+    /// Total bytes allocated, including what reallocations grew by.
+    pub bytes: u64,
+    /// Most bytes that were live at once, over what was live before.
+    pub peak: u64,
+}
+
+/// Set by the first allocation through [`Counting`], i.e. when it is the global allocator.
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static COUNT: Cell<u64> = const { Cell::new(0) };
+    static BYTES: Cell<u64> = const { Cell::new(0) };
+    static LIVE: Cell<i64> = const { Cell::new(0) };
+    static PEAK: Cell<i64> = const { Cell::new(0) };
+}
+
+/// Records that `grown` more bytes were allocated, and `freed` freed, by one (re)allocation.
+fn record(grown: usize, freed: usize) {
+    // Accessing these can't fail, as they have no destructor, but an allocator must never panic
+    let _ = COUNT.try_with(|count| count.set(count.get() + 1));
+    let _ = BYTES.try_with(|bytes| bytes.set(bytes.get() + grown as u64));
+    let _ = LIVE.try_with(|live| {
+        live.set(live.get() + grown as i64 - freed as i64);
+        let _ = PEAK.try_with(|peak| peak.set(peak.get().max(live.get())));
+    });
+}
+
+fn release(size: usize) {
+    let _ = LIVE.try_with(|live| live.set(live.get() - size as i64));
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if !INSTALLED.load(Ordering::Relaxed) {
+            INSTALLED.store(true, Ordering::Relaxed);
+        }
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        if !INSTALLED.load(Ordering::Relaxed) {
+            INSTALLED.store(true, Ordering::Relaxed);
+        }
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        release(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(
+                new_size.saturating_sub(layout.size()),
+                layout.size().saturating_sub(new_size),
+            );
+        }
+        new_ptr
+    }
+}
+
+/// Whether [`Counting`] is the global allocator, i.e. whether [`measure`] can count anything.
+pub fn installed() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+/// Calls `f`, and returns its result along with what it allocated on this thread, or `None` if
+/// [`Counting`] is not the global allocator. Calls can be nested: the peak `f` reaches still
+/// counts towards the peak of a `measure` around it.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<Allocations>) {
+    let count = COUNT.with(Cell::get);
+    let bytes = BYTES.with(Cell::get);
+    let live = LIVE.with(Cell::get);
+    let outer_peak = PEAK.with(|peak| peak.replace(live));
+
+    let result = f();
+
+    let inner_peak = PEAK.with(|peak| peak.replace(outer_peak.max(peak.get())));
+    let allocations = Allocations {
+        count: COUNT.with(Cell::get) - count,
+        bytes: BYTES.with(Cell::get) - bytes,
+        peak: (inner_peak - live).max(0) as u64,
+    };
+    (result, installed().then_some(allocations))
+}
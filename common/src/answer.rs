@@ -0,0 +1,71 @@
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+
+/// The answer to one part of a puzzle.
+///
+/// Most puzzles have an integer answer, but the signedness and width differ per day, so all
+/// integers are widened to an `i128`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    /// The input is well-formed, but the puzzle can't be solved for it: there is no path to the
+    /// end at all, say, or the numbers involved grow too large. Real puzzle inputs always have an
+    /// answer.
+    Unsolvable,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+            Answer::Unsolvable => write!(f, "no solution"),
+        }
+    }
+}
+
+/// Integers become JSON numbers, text becomes a string and no solution becomes `null`.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Int(value) => serializer.serialize_i128(*value),
+            Answer::Text(value) => serializer.serialize_str(value),
+            Answer::Unsolvable => serializer.serialize_none(),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Int(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// For the parts that can turn out to have no solution.
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Answer::Unsolvable, Into::into)
+    }
+}
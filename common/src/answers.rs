@@ -0,0 +1,67 @@
+//! Recorded answers, stored per day in `dayNN/answers.txt` (and `dayNN/fixtures/answers.txt` for
+//! the examples).
+//!
+//! Every non-empty line that does not start with `#` records the answers for one input file:
+//!
+//! ```text
+//! # input      part 1   part 2
+//! input.txt    53651    53894
+//! ```
+//!
+//! The input path is relative to the day's directory. An answer of `?` means it is not known yet.
+
+use crate::parse::lines;
+use crate::ParseError;
+
+/// The recorded answers for a single input file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recorded {
+    pub input: String,
+    /// Expected answers for part 1 and 2, `None` if not known yet.
+    pub answers: [Option<String>; 2],
+}
+
+impl Recorded {
+    /// The expected answer for `part` (1 or 2), if it is known.
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        self.answers[part as usize - 1].as_deref()
+    }
+}
+
+/// Parses the contents of an answers file.
+pub fn parse(text: &str) -> Result<Vec<Recorded>, ParseError> {
+    let mut recorded = Vec::new();
+    for line in lines(text) {
+        let trimmed = line.text.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let fields = trimmed.split_whitespace().collect::<Vec<_>>();
+        let [input, part1, part2] = fields[..] else {
+            return Err(line.error(
+                trimmed,
+                "expected an input file followed by the part 1 and part 2 answers",
+            ));
+        };
+        let answer = |s: &str| match s {
+            "?" => None,
+            _ => Some(s.to_string()),
+        };
+        recorded.push(Recorded {
+            input: input.to_string(),
+            answers: [answer(part1), answer(part2)],
+        });
+    }
+    Ok(recorded)
+}
+
+/// Reads the answers file at `path`. A missing file means nothing has been recorded yet.
+#[cfg(feature = "std")]
+pub fn load(path: &str) -> std::io::Result<Option<String>> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => Ok(Some(text)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
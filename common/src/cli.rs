@@ -0,0 +1,110 @@
+//! The command line of the per-day binaries:
+//!
+//! ```text
+//! cargo run --release -- [--format text|json] [INPUT]
+//! ```
+//!
+//! `INPUT` defaults to `input.txt`, and `-` reads the input from stdin. `--format json` prints one
+//! [`Record`](crate::runner::Record) per line instead of `Part N: answer`.
+
+use std::process::ExitCode;
+
+use crate::runner::{self, Run};
+use crate::{read_text, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format '{}', expected 'text' or 'json'", s)),
+        }
+    }
+}
+
+struct Args {
+    input: String,
+    format: Format,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut input = None;
+    let mut format = Format::Text;
+    while let Some(arg) = args.next() {
+        if let Some(value) = arg.strip_prefix("--format=") {
+            format = value.parse()?;
+        } else if arg == "--format" {
+            format = args.next().ok_or("--format needs a value")?.parse()?;
+        } else if arg.starts_with("--") {
+            return Err(format!("unknown option '{}'", arg));
+        } else if input.replace(arg).is_some() {
+            return Err("expected at most one input file".to_string());
+        }
+    }
+    Ok(Args {
+        input: input.unwrap_or_else(|| "input.txt".to_string()),
+        format,
+    })
+}
+
+/// The `main` of a day's binary. `package` is the crate name (`dayNN`), which tells which day
+/// this is.
+pub fn main<S: Solution>(package: &str) -> ExitCode {
+    let day = package
+        .strip_prefix("day")
+        .and_then(|day| day.parse().ok())
+        .unwrap_or_else(|| panic!("'{}' is not named after a day", package));
+
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{}", err);
+            eprintln!("usage: {} [--format text|json] [INPUT]", package);
+            return ExitCode::from(2);
+        }
+    };
+
+    let text = match read_text(&args.input) {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("{}: failed to read input: {}", args.input, err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let run = match runner::run::<S>(&text, None) {
+        Ok(run) => run,
+        Err(err) => {
+            eprintln!("{}: {}", args.input, err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    print(day, &run, args.format);
+    ExitCode::SUCCESS
+}
+
+fn print(day: u8, run: &Run, format: Format) {
+    match format {
+        Format::Text => {
+            for part in &run.parts {
+                println!("Part {}: {}", part.part, part.answer);
+            }
+        }
+        Format::Json => {
+            for record in run.records(day) {
+                println!(
+                    "{}",
+                    serde_json::to_string(&record).expect("records serialize")
+                );
+            }
+        }
+    }
+}
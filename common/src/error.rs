@@ -0,0 +1,78 @@
+use std::{fmt, io};
+
+/// A problem with the puzzle input, pointing at the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The offending text. Empty if something is missing.
+    pub text: String,
+    /// What was expected instead.
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        match self.text.is_empty() {
+            true => write!(f, " (found nothing)"),
+            false => write!(f, " (found '{}')", self.text),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Anything that can go wrong while reading a puzzle input.
+#[derive(Debug)]
+pub enum InputError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Io(err) => write!(f, "failed to read input: {}", err),
+            InputError::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Io(err) => Some(err),
+            InputError::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for InputError {
+    fn from(err: io::Error) -> Self {
+        InputError::Io(err)
+    }
+}
+
+impl From<ParseError> for InputError {
+    fn from(err: ParseError) -> Self {
+        InputError::Parse(err)
+    }
+}
@@ -0,0 +1,132 @@
+//! Runs a day's worked examples, stored as `dayNN/fixtures/*.txt`.
+//!
+//! The expected answers live next to them in `fixtures/answers.txt`, in the same format as the
+//! day's own `answers.txt`. Parts marked `?` are not run, so an example that only makes sense for
+//! one of the parts can leave the other one out.
+//!
+//! Inputs that once crashed a fuzz target live in `fixtures/regressions/`. They have no expected
+//! answers: it is enough that each one is either rejected by the parser or solved without a panic,
+//! within [`REGRESSION_BUDGET`].
+
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use crate::{answers, fuzz, Answer, Solution};
+
+type Part<I> = fn(&I) -> Answer;
+
+/// How long a fuzz regression may take before it counts as a hang. Generous, as tests are usually
+/// built without optimisations.
+pub const REGRESSION_BUDGET: Duration = Duration::from_secs(10);
+
+/// Checks every fixture in `dir` against its recorded answers, with the variants of each part as
+/// well, and runs the fuzz regressions in `dir/regressions`, panicking with a list of all
+/// failures. Every `.txt` file in `dir` has to be listed in `answers.txt`, so a new example can't
+/// be silently skipped.
+pub fn check<S: Solution + 'static>(dir: &str) {
+    let dir = Path::new(dir);
+    let answers_path = dir.join("answers.txt");
+    let text = fs::read_to_string(&answers_path)
+        .unwrap_or_else(|err| panic!("{}: {}", answers_path.display(), err));
+    let recorded =
+        answers::parse(&text).unwrap_or_else(|err| panic!("{}: {}", answers_path.display(), err));
+
+    let mut failures = Vec::new();
+    for entry in fs::read_dir(dir).unwrap_or_else(|err| panic!("{}: {}", dir.display(), err)) {
+        let name = entry.unwrap().file_name().to_string_lossy().into_owned();
+        if name.ends_with(".txt")
+            && name != "answers.txt"
+            && !recorded.iter().any(|r| r.input == name)
+        {
+            failures.push(format!("{}: no expected answers in answers.txt", name));
+        }
+    }
+
+    for fixture in &recorded {
+        let path = dir.join(&fixture.input);
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(err) => {
+                failures.push(format!("{}: {}", fixture.input, err));
+                continue;
+            }
+        };
+        let input = match S::parse(&input) {
+            Ok(input) => input,
+            Err(err) => {
+                failures.push(format!("{}: {}", fixture.input, err));
+                continue;
+            }
+        };
+
+        // The variants have to get the answers of their part right as well
+        let mut parts: Vec<(u8, String, Part<S::Input>)> = vec![
+            (1, "part 1".to_string(), S::part1),
+            (2, "part 2".to_string(), S::part2),
+        ];
+        for variant in S::variants() {
+            let label = format!("part {} ({})", variant.part, variant.name);
+            parts.push((variant.part, label, variant.solve));
+        }
+        for (part, label, solve) in parts {
+            let Some(expected) = fixture.expected(part) else {
+                continue;
+            };
+            match panic::catch_unwind(AssertUnwindSafe(|| solve(&input))) {
+                Ok(answer) if answer.to_string() == expected => {}
+                Ok(answer) => failures.push(format!(
+                    "{}: {} is {}, expected {}",
+                    fixture.input, label, answer, expected
+                )),
+                Err(_) => failures.push(format!("{}: {} panicked", fixture.input, label)),
+            }
+        }
+    }
+
+    let regressions = dir.join("regressions");
+    if let Ok(entries) = fs::read_dir(&regressions) {
+        for entry in entries {
+            let path = entry.unwrap().path();
+            let name = format!(
+                "regressions/{}",
+                path.file_name().unwrap().to_string_lossy()
+            );
+            match fs::read(&path) {
+                Ok(data) => {
+                    if let Some(failure) = run_regression::<S>(data) {
+                        failures.push(format!("{}: {}", name, failure));
+                    }
+                }
+                Err(err) => failures.push(format!("{}: {}", name, err)),
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!(
+            "{} example(s) failed:\n  {}",
+            failures.len(),
+            failures.join("\n  ")
+        );
+    }
+}
+
+/// Runs a fuzz regression on a thread of its own, waiting for at most [`REGRESSION_BUDGET`], so
+/// one that hangs fails the test rather than holding it up. Returns what went wrong, if anything.
+fn run_regression<S: Solution + 'static>(data: Vec<u8>) -> Option<String> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let result = panic::catch_unwind(|| fuzz::run::<S>(&data));
+        // The receiver is gone if it gave up waiting, which is fine
+        let _ = sender.send(result);
+    });
+    match receiver.recv_timeout(REGRESSION_BUDGET) {
+        Ok(Ok(())) => None,
+        Ok(Err(_)) => Some("panicked".to_string()),
+        Err(_) => Some(format!("took longer than {:?}", REGRESSION_BUDGET)),
+    }
+}
@@ -0,0 +1,19 @@
+//! The entry point of the fuzz targets in `fuzz/`, shared with the regression tests.
+//!
+//! A fuzz target feeds arbitrary bytes to [`run`]: anything that isn't UTF-8 or that the day's
+//! parser rejects is fine, but an input that parses has to be solvable, so a panic (or a hang)
+//! in either the parser or the solution is a bug. Inputs that found one are kept as
+//! `dayNN/fixtures/regressions/*`, and [`crate::fixtures::check`] runs them with every test.
+
+use crate::Solution;
+
+/// Parses `data` and, if that succeeds, solves both parts.
+pub fn run<S: Solution>(data: &[u8]) {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+    if let Ok(input) = S::parse(text) {
+        S::part1(&input);
+        S::part2(&input);
+    }
+}
@@ -0,0 +1,81 @@
+//! Random puzzle inputs, for stress tests, fuzzing and benchmarks at other sizes than the single
+//! checked-in `input.txt`.
+//!
+//! Every day implements [`Generate`] in its `src/generate.rs`. The generated inputs follow the
+//! same rules as the real ones (as far as the solutions rely on them), so both parts can always be
+//! solved.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::{Answer, Rng, Solution};
+
+pub trait Generate: Solution {
+    /// The size that roughly matches the real puzzle input.
+    const DEFAULT_SIZE: usize;
+
+    /// Generates a valid puzzle input. `size` scales the input: usually it is the number of lines,
+    /// or the width and height of a grid. Each day documents what it means for its input, and
+    /// clamps it to whatever minimum the puzzle needs.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// Generates the input for `seed`: the same seed and size always give the same input.
+pub fn generate<S: Generate>(seed: u64, size: usize) -> String {
+    S::generate(&mut Rng::new(seed), size)
+}
+
+/// The sizes and seeds the checks run every day's generator with. Small, so the slower solutions
+/// (and brute-force variants) stay quick.
+pub(crate) const SIZES: [usize; 8] = [0, 1, 2, 3, 5, 8, 13, 20];
+pub(crate) const SEEDS: std::ops::Range<u64> = 0..8;
+
+/// Checks a day's generator over a spread of seeds and (small) sizes: the inputs have to be
+/// deterministic, parse, and have a solution that is found without panicking. Panics with a list
+/// of all failures.
+pub fn check<S: Generate>() {
+    let mut failures = Vec::new();
+    for size in SIZES {
+        for seed in SEEDS {
+            let text = generate::<S>(seed, size);
+            if text != generate::<S>(seed, size) {
+                failures.push(format!("seed {}, size {}: not deterministic", seed, size));
+                continue;
+            }
+            let input = match S::parse(&text) {
+                Ok(input) => input,
+                Err(err) => {
+                    failures.push(format!("seed {}, size {}: {}", seed, size, err));
+                    continue;
+                }
+            };
+            let parts: [fn(&S::Input) -> Answer; 2] = [S::part1, S::part2];
+            for (part, solve) in (1..=2).zip(parts) {
+                let problem = match panic::catch_unwind(AssertUnwindSafe(|| solve(&input))) {
+                    Ok(Answer::Unsolvable) => "has no solution",
+                    Ok(_) => continue,
+                    Err(_) => "panicked",
+                };
+                failures.push(format!(
+                    "seed {}, size {}: part {} {}",
+                    seed, size, part, problem
+                ));
+            }
+        }
+    }
+
+    let size = S::DEFAULT_SIZE;
+    if generate::<S>(0, size) == generate::<S>(1, size) {
+        failures.push(format!(
+            "seeds 0 and 1 give the same input of size {}",
+            size
+        ));
+    }
+
+    if !failures.is_empty() {
+        panic!(
+            "{} generated input(s) failed:\n  {}",
+            failures.len(),
+            failures.join("\n  ")
+        );
+    }
+}
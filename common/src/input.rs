@@ -0,0 +1,15 @@
+//! Reading puzzle inputs, from a file or from stdin.
+
+use std::io::{self, Read};
+
+/// Reads the whole input file at `path`, or stdin if `path` is `-`.
+pub fn read_text(path: &str) -> io::Result<String> {
+    match path {
+        "-" => {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text)?;
+            Ok(text)
+        }
+        _ => std::fs::read_to_string(path),
+    }
+}
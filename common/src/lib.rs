@@ -0,0 +1,33 @@
+//! Shared building blocks for the individual day crates.
+//!
+//! The `std` feature (on by default) adds everything that needs an operating system: reading
+//! inputs from files and stdin, the runner and its timings, the per-day command line, and the
+//! fixture tests. Without it, this crate and the day crates build for WebAssembly.
+
+#[cfg(feature = "std")]
+pub mod alloc;
+mod answer;
+pub mod answers;
+#[cfg(feature = "std")]
+pub mod cli;
+mod error;
+#[cfg(feature = "std")]
+pub mod fixtures;
+pub mod fuzz;
+pub mod generate;
+#[cfg(feature = "std")]
+mod input;
+pub mod parse;
+mod rng;
+#[cfg(feature = "std")]
+pub mod runner;
+mod solution;
+pub mod variants;
+
+pub use answer::Answer;
+pub use error::{InputError, ParseError};
+pub use generate::Generate;
+#[cfg(feature = "std")]
+pub use input::read_text;
+pub use rng::Rng;
+pub use solution::{Solution, Variant};
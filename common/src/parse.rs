@@ -0,0 +1,90 @@
+//! Helpers for parsing puzzle inputs while keeping track of where each piece of text came from,
+//! so that malformed inputs can be reported as a [`ParseError`] rather than a panic.
+
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// A single line of puzzle input, together with its (1-based) line number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// Iterates over the lines of `input`, numbering them from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(idx, text)| Line {
+        number: idx + 1,
+        text,
+    })
+}
+
+/// Splits `input` into blocks of consecutive non-empty lines.
+pub fn sections(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut sections = vec![Vec::new()];
+    for line in lines(input) {
+        match line.text.is_empty() {
+            true => sections.push(Vec::new()),
+            false => sections.last_mut().unwrap().push(line),
+        }
+    }
+    sections.retain(|section| !section.is_empty());
+    sections
+}
+
+impl<'a> Line<'a> {
+    /// The 1-based column at which `token` starts. `token` is expected to be a slice of this
+    /// line; if it is not, the start of the line is used.
+    pub fn column_of(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize).wrapping_sub(start);
+        match offset <= self.text.len() && self.text.is_char_boundary(offset) {
+            true => self.text[..offset].chars().count() + 1,
+            false => 1,
+        }
+    }
+
+    /// An error pointing at `token`, which should be a slice of this line.
+    pub fn error(&self, token: &str, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.column_of(token), token, message)
+    }
+
+    /// An error for something that is missing at the end of this line.
+    pub fn error_at_end(&self, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.text.chars().count() + 1, "", message)
+    }
+
+    /// Like [`str::split_once`], but reports a missing delimiter as an error.
+    pub fn split_once(
+        &self,
+        s: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error(s, format!("expected '{}'", delimiter)))
+    }
+
+    /// Like [`str::strip_prefix`], but reports a missing prefix as an error.
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format!("expected '{}'", prefix)))
+    }
+
+    /// Parses `token`, describing it as `what` if it turns out to be invalid.
+    pub fn parse<T: FromStr>(&self, token: &'a str, what: &str) -> Result<T, ParseError> {
+        match token.is_empty() {
+            true => Err(self.error(token, format!("expected {}", what))),
+            false => token
+                .parse()
+                .map_err(|_| self.error(token, format!("expected {}", what))),
+        }
+    }
+
+    /// Parses every whitespace separated token in `s`.
+    pub fn parse_all<T: FromStr>(&self, s: &'a str, what: &str) -> Result<Vec<T>, ParseError> {
+        s.split_whitespace()
+            .map(|token| self.parse(token, what))
+            .collect()
+    }
+}
@@ -0,0 +1,141 @@
+//! Running a solution on an input, timing the parsing and each part separately, and counting what
+//! they allocate if the binary installed [`alloc::Counting`](crate::alloc::Counting).
+
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tracing::info_span;
+
+use crate::alloc::{self, Allocations};
+use crate::{Answer, ParseError, Solution, Variant};
+
+/// The answers from running a solution on one input, along with how long everything took.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub parse_time: Duration,
+    /// What parsing allocated, if allocations are counted.
+    pub parse_allocations: Option<Allocations>,
+    pub parts: Vec<PartRun>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub part: u8,
+    /// The [`Variant`] that solved the part, if not the part itself.
+    pub variant: Option<&'static str>,
+    pub answer: Answer,
+    pub time: Duration,
+    /// What solving the part allocated, if allocations are counted.
+    pub allocations: Option<Allocations>,
+}
+
+/// One part of one day, as printed with `--format json`.
+#[derive(Debug, Clone, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant: Option<&'static str>,
+    pub answer: Answer,
+    pub parse_time_ns: u64,
+    pub solve_time_ns: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_allocations: Option<Allocations>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solve_allocations: Option<Allocations>,
+}
+
+type Part<S> = fn(&<S as Solution>::Input) -> Answer;
+
+/// Parses `input` and runs the requested part(s) of a solution on it.
+pub fn run<S: Solution>(input: &str, part: Option<u8>) -> Result<Run, ParseError> {
+    run_parts::<S>(input, part, None)
+}
+
+/// Like [`run`], but solves the part that the variant called `name` belongs to with that variant.
+/// Panics if the day has no such variant.
+pub fn run_variant<S: Solution>(
+    input: &str,
+    part: Option<u8>,
+    name: &str,
+) -> Result<Run, ParseError> {
+    let variant = S::variants()
+        .into_iter()
+        .find(|variant| variant.name == name)
+        .unwrap_or_else(|| panic!("there is no variant called '{}'", name));
+    run_parts::<S>(input, part, Some(variant))
+}
+
+fn run_parts<S: Solution>(
+    input: &str,
+    part: Option<u8>,
+    variant: Option<Variant<S::Input>>,
+) -> Result<Run, ParseError> {
+    let start = Instant::now();
+    let (input, parse_allocations) =
+        info_span!("parse").in_scope(|| alloc::measure(|| S::parse(input)));
+    let parse_time = start.elapsed();
+    let input = input?;
+
+    let mut parts = Vec::new();
+    for (number, solve) in [(1, S::part1 as Part<S>), (2, S::part2)] {
+        if part.is_some_and(|part| part != number) {
+            continue;
+        }
+        let (variant, solve) = match &variant {
+            Some(variant) if variant.part == number => (Some(variant.name), variant.solve),
+            _ => (None, solve),
+        };
+        let span = info_span!("part", part = number, variant);
+        let start = Instant::now();
+        let (answer, allocations) = span.in_scope(|| alloc::measure(|| solve(&input)));
+        parts.push(PartRun {
+            part: number,
+            variant,
+            answer,
+            time: start.elapsed(),
+            allocations,
+        });
+    }
+    Ok(Run {
+        parse_time,
+        parse_allocations,
+        parts,
+    })
+}
+
+impl Run {
+    /// The answers in the form printed with `--format json`.
+    pub fn records(&self, day: u8) -> Vec<Record> {
+        self.parts
+            .iter()
+            .map(|part| Record {
+                day,
+                part: part.part,
+                variant: part.variant,
+                answer: part.answer.clone(),
+                parse_time_ns: self.parse_time.as_nanos() as u64,
+                solve_time_ns: part.time.as_nanos() as u64,
+                parse_allocations: self.parse_allocations,
+                solve_allocations: part.allocations,
+            })
+            .collect()
+    }
+}
+
+/// Formats a duration with 4 significant digits and a fitting unit, e.g. `7.938 µs`. Used
+/// wherever timings are printed, so `aoc run` and the benchmark table read the same.
+pub fn format_duration(duration: Duration) -> String {
+    let ns = duration.as_nanos() as f64;
+    let (value, unit) = match ns {
+        ns if ns < 1e3 => (ns, "ns"),
+        ns if ns < 1e6 => (ns / 1e3, "µs"),
+        ns if ns < 1e9 => (ns / 1e6, "ms"),
+        ns => (ns / 1e9, "s"),
+    };
+    match value {
+        v if v < 10.0 => format!("{:.3} {}", v, unit),
+        v if v < 100.0 => format!("{:.2} {}", v, unit),
+        v => format!("{:.1} {}", v, unit),
+    }
+}
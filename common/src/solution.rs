@@ -0,0 +1,49 @@
+use std::io::Read;
+
+use crate::{Answer, InputError, ParseError};
+
+/// A single day's puzzle: how to parse its input, and how to solve both parts.
+///
+/// Every `dayNN` crate exposes a unit struct (e.g. `day17::Day17`) implementing this trait, so
+/// tooling such as the runner can treat all days the same way.
+pub trait Solution {
+    /// The parsed puzzle input, shared by both parts.
+    type Input;
+
+    /// Parses the puzzle input from its text.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Reads the puzzle input from `reader` and parses it.
+    fn parse_reader(mut reader: impl Read) -> Result<Self::Input, InputError> {
+        let mut data = String::new();
+        reader.read_to_string(&mut data)?;
+        Ok(Self::parse(&data)?)
+    }
+
+    /// Reads and parses the puzzle input stored in `filename`, or stdin if `filename` is `-`.
+    #[cfg(feature = "std")]
+    fn read_input(filename: &str) -> Result<Self::Input, InputError> {
+        Ok(Self::parse(&crate::read_text(filename)?)?)
+    }
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Other ways of solving the parts, such as a brute-force reference or an optimized rewrite.
+    /// Most days have none.
+    fn variants() -> Vec<Variant<Self::Input>> {
+        Vec::new()
+    }
+}
+
+/// An alternative implementation of one part of a [`Solution`], selectable with
+/// `aoc run --variant`. It has to give the same answers as the part itself, which
+/// [`variants::check`](crate::variants::check) tests.
+pub struct Variant<I> {
+    /// Short name to select it by, unique within the day.
+    pub name: &'static str,
+    /// The part it solves, 1 or 2.
+    pub part: u8,
+    pub solve: fn(&I) -> Answer,
+}
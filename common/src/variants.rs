@@ -0,0 +1,94 @@
+//! Differential tests: every [`Variant`](crate::Variant) of a day has to give the same answer as
+//! the part it stands in for, on any input that the part can solve.
+//!
+//! [`check`] compares them on generated inputs. A day whose generator rarely hits the corner cases
+//! the implementations disagree on (overlapping words, say) can feed its own inputs to
+//! [`check_inputs`] as well.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::generate::{self, Generate, SEEDS, SIZES};
+use crate::{Answer, Solution};
+
+/// How many disagreements to list before giving up, as one bug tends to cause a lot of them.
+const MAX_FAILURES: usize = 20;
+
+/// Compares every variant of a day with its part, on inputs generated with the same seeds and
+/// sizes as [`generate::check`]. Panics with a list of the disagreements.
+pub fn check<S: Generate>() {
+    let inputs = SIZES.into_iter().flat_map(|size| {
+        SEEDS.map(move |seed| {
+            let label = format!("seed {}, size {}", seed, size);
+            (label, generate::generate::<S>(seed, size))
+        })
+    });
+    report(compare::<S>(inputs));
+}
+
+/// Compares every variant of a day with its part on each of `inputs`, which all have to parse.
+/// Panics with a list of the disagreements.
+pub fn check_inputs<S: Solution>(inputs: impl IntoIterator<Item = String>) {
+    let inputs = inputs.into_iter().enumerate().map(|(idx, text)| {
+        // Short inputs are easier to read than to look up
+        let label = match text.len() <= 60 {
+            true => format!("{:?}", text),
+            false => format!("input {}", idx),
+        };
+        (label, text)
+    });
+    report(compare::<S>(inputs));
+}
+
+fn compare<S: Solution>(inputs: impl Iterator<Item = (String, String)>) -> Vec<String> {
+    let variants = S::variants();
+    assert!(!variants.is_empty(), "there are no variants to compare");
+
+    let mut failures = Vec::new();
+    for (label, text) in inputs {
+        let input = match S::parse(&text) {
+            Ok(input) => input,
+            Err(err) => {
+                failures.push(format!("{}: {}", label, err));
+                continue;
+            }
+        };
+        let solve = |solve: fn(&S::Input) -> Answer| {
+            panic::catch_unwind(AssertUnwindSafe(|| solve(&input))).ok()
+        };
+
+        let expected = [solve(S::part1), solve(S::part2)];
+        for variant in &variants {
+            let answer = solve(variant.solve);
+            let part = variant.part;
+            let problem = match (&expected[part as usize - 1], answer) {
+                (None, _) => format!("part {} panicked", part),
+                (Some(_), None) => format!("{} panicked", variant.name),
+                (Some(expected), Some(answer)) if *expected == answer => continue,
+                (Some(expected), Some(answer)) => format!(
+                    "part {} is {}, but {} gives {}",
+                    part, expected, variant.name, answer
+                ),
+            };
+            failures.push(format!("{}: {}", label, problem));
+        }
+    }
+    failures
+}
+
+fn report(failures: Vec<String>) {
+    if failures.is_empty() {
+        return;
+    }
+    let mut listed = failures[..failures.len().min(MAX_FAILURES)].join("\n  ");
+    if failures.len() > MAX_FAILURES {
+        listed.push_str(&format!(
+            "\n  ... and {} more",
+            failures.len() - MAX_FAILURES
+        ));
+    }
+    panic!(
+        "{} disagreement(s) between variants:\n  {}",
+        failures.len(),
+        listed
+    );
+}
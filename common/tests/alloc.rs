@@ -0,0 +1,112 @@
+use common::alloc::{self, Allocations, Counting};
+use common::parse::lines;
+use common::runner::run;
+use common::{Answer, ParseError, Solution};
+use std::hint::black_box;
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// Parses a line per number, and allocates a vector of the given length per number in part 2.
+struct Toy;
+
+impl Solution for Toy {
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        lines(input)
+            .map(|line| line.parse(line.text, "a number"))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.iter().sum::<usize>().into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let lengths: Vec<usize> = input
+            .iter()
+            .map(|&len| black_box(vec![0u8; len]).len())
+            .collect();
+        lengths.len().into()
+    }
+}
+
+#[test]
+fn counts_allocations() {
+    let (sum, allocations) = alloc::measure(|| {
+        let first = black_box(vec![0u8; 1000]);
+        let second = black_box(vec![0u8; 500]);
+        first.len() + second.len()
+    });
+    assert_eq!(sum, 1500);
+    assert_eq!(
+        allocations,
+        Some(Allocations {
+            count: 2,
+            bytes: 1500,
+            peak: 1500
+        })
+    );
+
+    // Freeing the first vector before allocating the second keeps the peak down
+    let ((), allocations) = alloc::measure(|| {
+        drop(black_box(vec![0u8; 1000]));
+        drop(black_box(vec![0u8; 500]));
+    });
+    assert_eq!(allocations.unwrap().peak, 1000);
+
+    let ((), allocations) = alloc::measure(|| {});
+    assert_eq!(allocations, Some(Allocations::default()));
+}
+
+#[test]
+fn nested_measures_keep_the_outer_peak() {
+    let (inner, outer) = alloc::measure(|| {
+        drop(black_box(vec![0u8; 1000]));
+        let (_, inner) = alloc::measure(|| black_box(vec![0u8; 300]));
+        inner
+    });
+    // The inner measure starts below the outer peak, and doesn't reset it
+    assert_eq!(inner.unwrap().peak, 300);
+    let outer = outer.unwrap();
+    assert_eq!((outer.count, outer.bytes, outer.peak), (2, 1300, 1000));
+
+    // But the inner peak counts towards the outer one when it's higher
+    let (_, outer) = alloc::measure(|| {
+        let _first = black_box(vec![0u8; 100]);
+        alloc::measure(|| drop(black_box(vec![0u8; 1000])));
+    });
+    assert_eq!(outer.unwrap().peak, 1100);
+}
+
+#[test]
+fn counts_reallocations() {
+    let (_, allocations) = alloc::measure(|| {
+        let mut v: Vec<u8> = black_box(Vec::with_capacity(100));
+        v.reserve_exact(200);
+        v
+    });
+    let allocations = allocations.unwrap();
+    assert_eq!(allocations.count, 2);
+    assert_eq!(allocations.bytes, 200);
+    assert_eq!(allocations.peak, 200);
+}
+
+#[test]
+fn runner_reports_allocations() {
+    let run = run::<Toy>("100\n200\n", None).unwrap();
+    assert!(run.parse_allocations.unwrap().count > 0);
+
+    let part1 = run.parts[0].allocations.unwrap();
+    assert_eq!(part1, Allocations::default());
+    // A vector of 100 and one of 200 bytes, one at a time, next to the vector of their lengths
+    let lengths = 2 * std::mem::size_of::<usize>() as u64;
+    let part2 = run.parts[1].allocations.unwrap();
+    assert_eq!(part2.count, 3);
+    assert_eq!(part2.bytes, 300 + lengths);
+
+    let json = serde_json::to_value(&run.records(1)[1]).unwrap();
+    assert_eq!(json["solve_allocations"]["count"], 3);
+    assert_eq!(json["solve_allocations"]["peak"], 200 + lengths);
+}
@@ -0,0 +1,34 @@
+use common::Rng;
+
+#[test]
+fn rng_is_deterministic() {
+    let numbers = |seed| {
+        let mut rng = Rng::new(seed);
+        (0..100).map(|_| rng.next_u64()).collect::<Vec<_>>()
+    };
+    assert_eq!(numbers(7), numbers(7));
+    assert_ne!(numbers(7), numbers(8));
+    // Pinned, so a change to the generator (and with it every generated input) doesn't go unnoticed
+    assert_eq!(Rng::new(0).next_u64(), 0xe220_a839_7b1d_cdaf);
+}
+
+#[test]
+fn rng_ranges() {
+    let mut rng = Rng::new(1);
+    let mut seen = [false; 6];
+    for _ in 0..1000 {
+        let n = rng.range(3..=8);
+        assert!((3..=8).contains(&n));
+        seen[n - 3] = true;
+        assert!((10..12).contains(&rng.range(10..12)));
+        assert_eq!(rng.range(5..=5), 5);
+    }
+    assert!(seen.iter().all(|&seen| seen));
+    rng.range(..);
+
+    let mut items: Vec<u32> = (0..50).collect();
+    rng.shuffle(&mut items);
+    assert_ne!(items, (0..50).collect::<Vec<_>>());
+    items.sort_unstable();
+    assert_eq!(items, (0..50).collect::<Vec<_>>());
+}
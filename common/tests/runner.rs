@@ -0,0 +1,163 @@
+use common::parse::lines;
+use common::runner::{format_duration, run, run_variant};
+use common::{Answer, ParseError, Solution, Variant};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+/// Sums the numbers in the input, and joins them with `-`.
+struct Toy;
+
+impl Solution for Toy {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        lines(input)
+            .map(|line| line.parse(line.text, "a number"))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.iter().sum::<i64>().into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let parts: Vec<String> = input.iter().map(i64::to_string).collect();
+        parts.join("-").into()
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![Variant {
+            name: "rev",
+            part: 1,
+            solve: |input| input.iter().rev().sum::<i64>().into(),
+        }]
+    }
+}
+
+#[test]
+fn runs_the_requested_parts() {
+    let both = run::<Toy>("1\n2\n", None).unwrap();
+    let parts: Vec<(u8, Answer)> = both
+        .parts
+        .iter()
+        .map(|part| (part.part, part.answer.clone()))
+        .collect();
+    assert_eq!(parts, [(1, Answer::Int(3)), (2, Answer::from("1-2"))]);
+
+    // This test binary doesn't count allocations, see tests/alloc.rs for one that does
+    assert_eq!(both.parse_allocations, None);
+    assert!(both.parts.iter().all(|part| part.allocations.is_none()));
+
+    let second = run::<Toy>("1\n2\n", Some(2)).unwrap();
+    assert_eq!(second.parts.len(), 1);
+    assert_eq!(second.parts[0].part, 2);
+
+    let err = run::<Toy>("1\nx\n", None).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 1));
+}
+
+#[test]
+fn json_records() {
+    let records = run::<Toy>("40\n2\n", None).unwrap().records(7);
+    let json: Vec<serde_json::Value> = records
+        .iter()
+        .map(|record| serde_json::to_value(record).unwrap())
+        .collect();
+
+    assert_eq!(json[0]["day"], 7);
+    assert_eq!(json[0]["part"], 1);
+    assert_eq!(json[0]["answer"], 42);
+    assert_eq!(json[1]["answer"], "40-2");
+    for record in &json {
+        let keys: Vec<&String> = record.as_object().unwrap().keys().collect();
+        assert_eq!(
+            keys,
+            ["answer", "day", "parse_time_ns", "part", "solve_time_ns"]
+        );
+        assert!(record["parse_time_ns"].is_u64());
+        assert!(record["solve_time_ns"].is_u64());
+    }
+    // Both parts share the same parse
+    assert_eq!(json[0]["parse_time_ns"], json[1]["parse_time_ns"]);
+}
+
+#[test]
+fn unsolvable_answers() {
+    assert_eq!(Answer::from(Some(3u8)), Answer::Int(3));
+    let none = Answer::from(None::<usize>);
+    assert_eq!(none, Answer::Unsolvable);
+    assert_eq!(none.to_string(), "no solution");
+    assert_eq!(
+        serde_json::to_value(&none).unwrap(),
+        serde_json::Value::Null
+    );
+}
+
+#[test]
+fn formats_durations() {
+    assert_eq!(format_duration(Duration::from_nanos(850)), "850.0 ns");
+    assert_eq!(format_duration(Duration::from_nanos(7_938)), "7.938 µs");
+    assert_eq!(format_duration(Duration::from_micros(42_170)), "42.17 ms");
+    assert_eq!(format_duration(Duration::from_millis(1_030)), "1.030 s");
+}
+
+#[test]
+fn runs_a_variant() {
+    let run = run_variant::<Toy>("1\n2\n", None, "rev").unwrap();
+    let parts: Vec<(u8, Option<&str>, Answer)> = run
+        .parts
+        .iter()
+        .map(|part| (part.part, part.variant, part.answer.clone()))
+        .collect();
+    assert_eq!(
+        parts,
+        [
+            (1, Some("rev"), Answer::Int(3)),
+            (2, None, Answer::from("1-2"))
+        ]
+    );
+
+    let json = serde_json::to_value(&run.records(1)[0]).unwrap();
+    assert_eq!(json["variant"], "rev");
+}
+
+/// Records the name and fields of every span that is opened.
+#[derive(Clone, Default)]
+struct Spans(Arc<Mutex<Vec<String>>>);
+
+impl<S: tracing::Subscriber> Layer<S> for Spans {
+    fn on_new_span(&self, attrs: &Attributes<'_>, _: &Id, _: Context<'_, S>) {
+        struct Fields(String);
+        impl Visit for Fields {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0 += &format!(" {}={:?}", field.name(), value);
+            }
+        }
+        let mut fields = Fields(attrs.metadata().name().to_string());
+        attrs.record(&mut fields);
+        self.0.lock().unwrap().push(fields.0);
+    }
+}
+
+#[test]
+fn spans_per_parse_and_part() {
+    let spans = Spans::default();
+    let subscriber = tracing_subscriber::registry().with(spans.clone());
+    tracing::subscriber::with_default(subscriber, || {
+        run::<Toy>("1\n2\n", None).unwrap();
+        run_variant::<Toy>("1\n2\n", Some(1), "rev").unwrap();
+    });
+    assert_eq!(
+        *spans.0.lock().unwrap(),
+        [
+            "parse",
+            "part part=1",
+            "part part=2",
+            "parse",
+            "part part=1 variant=\"rev\""
+        ]
+    );
+}
@@ -1,11 +1,18 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use day01::{part1, part2, part2a, read_input};
+use day01::{parse, part1, part2, part2a};
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let input = read_input("input.txt");
-    c.bench_function("part1", |b| b.iter(|| part1(black_box(&input))));
-    c.bench_function("part2", |b| b.iter(|| part2(black_box(&input))));
-    c.bench_function("part2a", |b| b.iter(|| part2a(black_box(&input))));
+    // Group by day, so that every crate's results end up in their own directory
+    let mut group = c.benchmark_group("day01");
+
+    let data = std::fs::read_to_string("input.txt").unwrap();
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(&data))));
+
+    let input = parse(&data).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&input))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&input))));
+    group.bench_function("part2a", |b| b.iter(|| part2a(black_box(&input))));
+    group.finish();
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -0,0 +1,44 @@
+//! Random calibration documents: `size` lines of letters, digits and spelled-out digits, each with
+//! at least one real digit.
+
+use common::{Generate, Rng};
+
+use crate::Day01;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+fn digit(rng: &mut Rng) -> char {
+    (b'1' + rng.below(9) as u8) as char
+}
+
+impl Generate for Day01 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut out = String::new();
+        for _ in 0..size.max(1) {
+            let mut line = String::new();
+            for _ in 0..rng.range(1..=8) {
+                match rng.below(3) {
+                    0 => line.push(digit(rng)),
+                    1 => line.push_str(WORDS[rng.below(WORDS.len())]),
+                    _ => {
+                        for _ in 0..rng.range(1..=5) {
+                            line.push((b'a' + rng.below(26) as u8) as char);
+                        }
+                    }
+                }
+            }
+            // Part 1 needs a digit on every line
+            if !line.bytes().any(|b| b.is_ascii_digit()) {
+                let idx = rng.range(0..=line.len());
+                line.insert(idx, digit(rng));
+            }
+            out.push_str(&line);
+            out.push('\n');
+        }
+        out
+    }
+}
@@ -1,16 +1,18 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use common::parse::lines;
+use common::{Answer, ParseError, Solution, Variant};
+
+mod generate;
 
 pub fn part1(input: &Vec<String>) -> usize {
     let mut sum: usize = 0;
 
     for line in input {
-        let first = line
-            .chars()
-            .find(char::is_ascii_digit)
-            .expect("No first digit found")
-            .to_digit(10)
-            .unwrap() as usize;
+        // Lines that only spell out their digits (like those of the part 2 example) have no
+        // calibration value in part 1
+        let Some(first) = line.chars().find(char::is_ascii_digit) else {
+            continue;
+        };
+        let first = first.to_digit(10).unwrap() as usize;
         let last = line
             .chars()
             .rfind(char::is_ascii_digit)
@@ -75,6 +77,7 @@ pub fn part2(input: &Vec<String>) -> usize {
 }
 
 #[repr(u32)]
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 enum State {
     Nothing,
@@ -110,10 +113,11 @@ type Transition = (State, u32);
 const fn create_table() -> [[Transition; 128]; 26]{
     let mut table = [[(State::Nothing, 0u32); 128]; 26];
 
-    // A digit in any position should always result in a transition to the Done state.
+    // A digit in any position should always result in a transition to the Done state. Zero
+    // doesn't count as a digit, just like in `part2`.
     let mut state = 0;
     while state < 26 {
-        let mut chr = 0x30usize;
+        let mut chr = 0x31usize;
         while chr < 0x3Ausize {
             table[state][chr] = (State::Done, (chr-0x30) as u32);
             chr += 1;
@@ -177,6 +181,7 @@ const fn create_table() -> [[Transition; 128]; 26]{
     table[State::N as usize]['i' as usize] = (State::NI, 0);
     table[State::ON as usize]['i' as usize] = (State::NI, 0);
     table[State::NI as usize]['n' as usize] = (State::NIN, 0);
+    table[State::NIN as usize]['i' as usize] = (State::NI, 0);
     table[State::NIN as usize]['e' as usize] = (State::Done, 9);
 
     table
@@ -209,12 +214,55 @@ pub fn part2a(input: &Vec<String>) -> usize {
 
 }
 
-pub fn read_input(filename: &str) -> Vec<String> {
-    let mut input = Vec::new();
-    let file = File::open(filename).expect("File not found");
-    let reader = BufReader::new(file);
-    for line in reader.lines() {
-        input.push(line.unwrap());
+pub fn parse(data: &str) -> Result<Vec<String>, ParseError> {
+    lines(data)
+        .map(|line| {
+            // The lookup table in `part2a` only covers ASCII, so reject anything else up front.
+            match line
+                .text
+                .char_indices()
+                .find(|(_, c)| !c.is_ascii_alphanumeric())
+            {
+                Some((idx, c)) => {
+                    let chr = &line.text[idx..idx + c.len_utf8()];
+                    Err(line.error(chr, "expected a letter or digit"))
+                }
+                None if !TOKENS.iter().any(|(token, _)| line.text.contains(token)) => {
+                    Err(line.error(line.text, "expected a line with at least one digit"))
+                }
+                None => Ok(line.text.to_string()),
+            }
+        })
+        .collect()
+}
+
+#[cfg(feature = "std")]
+pub fn read_input(filename: &str) -> Result<Vec<String>, common::InputError> {
+    Day01::read_input(filename)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![Variant {
+            name: "dfa",
+            part: 2,
+            solve: |input| part2a(input).into(),
+        }]
     }
-    input
 }
@@ -1,13 +1,7 @@
-use day01::{part1, part2, part2a, read_input};
+use day01::Day01;
 
-fn main() {
-    let input = read_input("input.txt");
-    let res = part1(&input);
-    println!("Part 1: {}", res); // 53651 (7.9387 µs)
-
-    let res = part2(&input);
-    println!("Part 2: {}", res); // 53894 (876.00 µs)
-
-    let res: usize = part2a(&input);
-    println!("Part 2a: {}", res); // 53894 (16.828 µs)
+// Usage: cargo run --release -- [--format text|json] [INPUT]
+// INPUT defaults to input.txt, and "-" reads stdin
+fn main() -> std::process::ExitCode {
+    common::cli::main::<Day01>(env!("CARGO_PKG_NAME"))
 }
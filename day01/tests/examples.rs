@@ -0,0 +1,6 @@
+use day01::Day01;
+
+#[test]
+fn examples() {
+    common::fixtures::check::<Day01>(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+}
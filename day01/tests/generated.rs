@@ -0,0 +1,6 @@
+use day01::Day01;
+
+#[test]
+fn generated() {
+    common::generate::check::<Day01>();
+}
@@ -0,0 +1,37 @@
+use common::Rng;
+use day01::Day01;
+
+#[test]
+fn variants() {
+    common::variants::check::<Day01>();
+}
+
+/// Pieces of the spelled-out digits, so that words overlap, break off and start over far more
+/// often than in the generated documents.
+const PIECES: [&str; 16] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "o", "t", "e", "n",
+    "ni", "se", "x",
+];
+
+/// A line of pieces and digits (0 included, which isn't a calibration digit), with at least one
+/// digit from 1 to 9 in it so that it parses.
+fn random_line(rng: &mut Rng) -> String {
+    let mut line = String::new();
+    for _ in 0..rng.range(1..=6) {
+        match rng.below(4) {
+            0 => line.push((b'0' + rng.below(10) as u8) as char),
+            _ => line.push_str(PIECES[rng.below(PIECES.len())]),
+        }
+    }
+    if !line.bytes().any(|b| (b'1'..=b'9').contains(&b)) {
+        line.push((b'1' + rng.below(9) as u8) as char);
+    }
+    line
+}
+
+#[test]
+fn overlapping_words() {
+    let mut rng = Rng::new(1);
+    let lines: Vec<String> = (0..10_000).map(|_| random_line(&mut rng)).collect();
+    common::variants::check_inputs::<Day01>(lines);
+}
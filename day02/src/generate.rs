@@ -0,0 +1,28 @@
+//! Random game records: `size` games of a few draws each.
+
+use common::{Generate, Rng};
+
+use crate::Day02;
+
+impl Generate for Day02 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut out = String::new();
+        for game in 1..=size.max(1) {
+            let draws: Vec<String> = (0..rng.range(1..=6))
+                .map(|_| {
+                    let mut colours = ["red", "green", "blue"];
+                    rng.shuffle(&mut colours);
+                    let cubes: Vec<String> = colours[..rng.range(1..=3)]
+                        .iter()
+                        .map(|colour| format!("{} {}", rng.range(1..=20), colour))
+                        .collect();
+                    cubes.join(", ")
+                })
+                .collect();
+            out.push_str(&format!("Game {}: {}\n", game, draws.join("; ")));
+        }
+        out
+    }
+}
@@ -1,51 +1,53 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use common::parse::{lines, Line};
+use common::{Answer, ParseError, Solution};
+
+mod generate;
 
 #[derive(Debug, Default)]
 struct Hand {
-    red: u32,
-    green: u32,
-    blue: u32,
+    red: u8,
+    green: u8,
+    blue: u8,
 }
 
 impl Hand {
-    fn from_str(hand: &str) -> Hand {
+    fn from_str<'a>(line: &Line<'a>, hand: &'a str) -> Result<Hand, ParseError> {
         let mut ret = Hand::default();
 
-        hand.split(", ").for_each(|group| {
-            let (num, color) = group.split_once(' ').unwrap();
-            let num = num.parse::<u32>().unwrap();
+        for group in hand.split(", ") {
+            let (num, color) = line.split_once(group, " ")?;
+            let num = line.parse::<u8>(num, "a number of cubes")?;
             match color {
                 "red" => ret.red = num,
                 "green" => ret.green = num,
                 "blue" => ret.blue = num,
-                _ => unreachable!(),
+                _ => return Err(line.error(color, "expected 'red', 'green' or 'blue'")),
             };
-        });
+        }
 
-        ret
+        Ok(ret)
     }
 }
 
 #[derive(Debug, Default)]
 pub struct Game {
-    id: usize,
+    id: u32,
     hands: Vec<Hand>,
 }
 
 impl Game {
-    fn from_line(line: &str) -> Game {
-        let (identifier, game) = line.split_once(": ").unwrap();
+    fn from_line(line: &Line) -> Result<Game, ParseError> {
+        let (identifier, game) = line.split_once(line.text, ": ")?;
 
-        let id = identifier
-            .strip_prefix("Game ")
-            .unwrap()
-            .parse::<usize>()
-            .unwrap();
+        let id = line.strip_prefix(identifier, "Game ")?;
+        let id = line.parse::<u32>(id, "a game id")?;
 
-        let hands = game.split("; ").map(Hand::from_str).collect::<Vec<Hand>>();
+        let hands = game
+            .split("; ")
+            .map(|hand| Hand::from_str(line, hand))
+            .collect::<Result<Vec<Hand>, ParseError>>()?;
 
-        Game { id, hands }
+        Ok(Game { id, hands })
     }
 }
 
@@ -64,7 +66,7 @@ pub fn part1(input: &Vec<Game>) -> usize {
                 continue 'game;
             }
         }
-        result += game.id;
+        result += game.id as usize;
     }
 
     result
@@ -87,18 +89,35 @@ pub fn part2(input: &Vec<Game>) -> usize {
                 min_hand.green = hand.green;
             }
         }
-        result += (min_hand.blue * min_hand.red * min_hand.green) as usize;
+        result += min_hand.blue as usize * min_hand.red as usize * min_hand.green as usize;
     }
 
     result
 }
 
-pub fn read_input(filename: &str) -> Vec<Game> {
-    let file = File::open(filename).expect("File not found");
-    let reader = BufReader::new(file);
-    reader
-        .lines()
-        .flatten()
-        .map(|line| Game::from_line(&line))
-        .collect()
+pub fn parse(data: &str) -> Result<Vec<Game>, ParseError> {
+    lines(data).map(|line| Game::from_line(&line)).collect()
+}
+
+#[cfg(feature = "std")]
+pub fn read_input(filename: &str) -> Result<Vec<Game>, common::InputError> {
+    Day02::read_input(filename)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
 }
@@ -1,10 +1,7 @@
-use day02::{part1, part2, read_input};
+use day02::Day02;
 
-fn main() {
-    let input = read_input("input.txt");
-    let res = part1(&input);
-    println!("Part 1: {}", res); // 1867 (180.02 ns)
-
-    let res = part2(&input);
-    println!("Part 2: {}", res); // 84538 (265.32 ns)
+// Usage: cargo run --release -- [--format text|json] [INPUT]
+// INPUT defaults to input.txt, and "-" reads stdin
+fn main() -> std::process::ExitCode {
+    common::cli::main::<Day02>(env!("CARGO_PKG_NAME"))
 }
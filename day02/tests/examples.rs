@@ -0,0 +1,6 @@
+use day02::Day02;
+
+#[test]
+fn examples() {
+    common::fixtures::check::<Day02>(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+}
@@ -0,0 +1,6 @@
+use day02::Day02;
+
+#[test]
+fn generated() {
+    common::generate::check::<Day02>();
+}
@@ -0,0 +1,42 @@
+//! Random engine schematics: a `size` by `size` grid of numbers (of up to 3 digits) and symbols.
+
+use common::{Generate, Rng};
+
+use crate::Day03;
+
+const SYMBOLS: &[u8] = b"*#+$/@%=-&";
+
+impl Generate for Day03 {
+    const DEFAULT_SIZE: usize = 140;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let mut out = String::new();
+        for _ in 0..size {
+            let mut row = Vec::with_capacity(size);
+            while row.len() < size {
+                if rng.chance(0.15) {
+                    let digits = rng.range(1..=3).min(size - row.len());
+                    row.push(b'1' + rng.below(9) as u8);
+                    for _ in 1..digits {
+                        row.push(b'0' + rng.below(10) as u8);
+                    }
+                    // Don't let the next number run into this one
+                    if row.len() < size {
+                        row.push(match rng.chance(0.2) {
+                            true => *rng.pick(SYMBOLS),
+                            false => b'.',
+                        });
+                    }
+                } else if rng.chance(0.05) {
+                    row.push(*rng.pick(SYMBOLS));
+                } else {
+                    row.push(b'.');
+                }
+            }
+            out.push_str(std::str::from_utf8(&row).unwrap());
+            out.push('\n');
+        }
+        out
+    }
+}
@@ -1,88 +1,82 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use common::{Answer, ParseError, Solution};
+use grid::Grid;
+
+mod generate;
 
 #[derive(Debug)]
 enum Elem {
     Empty,
     Symbol(char),
     /// Part Number (id, value)
-    Number(u32, u32),
+    Number(u32, u16),
 }
 
 #[derive(Debug)]
 pub struct Schematic {
-    w: usize,
-    h: usize,
-    data: Vec<Vec<Elem>>,
+    data: Grid<Elem>,
 }
 
 impl Schematic {
-    fn from_vec(s: &Vec<String>) -> Option<Self> {
-        let w = s[0].len();
-        let h = s.len();
+    fn from_grid(s: &Grid<u8>) -> Result<Self, ParseError> {
+        let w = s.width();
 
         let mut cur_id = 1;
 
-        let mut data = Vec::new();
-        for line in s {
-            let mut row = Vec::new();
+        let mut data = Vec::with_capacity(s.cells().len());
+        for (y, line) in s.rows().enumerate() {
             let mut idx = 0usize;
-            let line = line.as_bytes();
             while idx < w {
                 match line[idx] {
                     b'.' => {
-                        row.push(Elem::Empty);
+                        data.push(Elem::Empty);
                     }
                     b'0'..=b'9' => {
+                        let start = idx;
                         let mut num = 1;
-                        let mut val = (line[idx] - b'0') as u32;
+                        let mut val = Some((line[idx] - b'0') as u16);
                         while line.get(idx + 1).is_some_and(|x| x.is_ascii_digit()) {
                             idx += 1;
-                            val = val * 10 + (line[idx] - b'0') as u32;
+                            val = val
+                                .and_then(|val| val.checked_mul(10))
+                                .and_then(|val| val.checked_add((line[idx] - b'0') as u16));
                             num += 1;
                         }
+                        let Some(val) = val else {
+                            let token = String::from_utf8_lossy(&line[start..=idx]);
+                            let message = "expected a part number below 65536";
+                            return Err(ParseError::new(y + 1, start + 1, &token, message));
+                        };
                         for _ in 0..num {
-                            row.push(Elem::Number(cur_id, val));
+                            data.push(Elem::Number(cur_id, val));
                         }
                         cur_id += 1;
                     }
-                    x => row.push(Elem::Symbol(x as char)),
+                    x => data.push(Elem::Symbol(x as char)),
                 }
                 idx += 1;
             }
-            data.push(row);
         }
-        Some(Self { w, h, data })
+        Ok(Self {
+            data: Grid::from_vec(w, data),
+        })
     }
 
     fn get_neighbors<'a>(&'a self, neighbors: &mut Vec<&'a Elem>, x: usize, y: usize) {
-        let x = x as isize;
-        let y = y as isize;
-        let mut seen = [0u32; 9];
+        let mut seen = [0u32; 8];
 
         // remove any stale entries from neighbors
         neighbors.clear();
 
-        for dx in (x - 1)..=(x + 1) {
-            for dy in (y - 1)..=(y + 1) {
-                if dx == x && dy == y {
+        for (idx, pos) in self.data.neighbours8(x, y).enumerate() {
+            let elem = &self.data[pos];
+            if let Elem::Number(id, _val) = elem {
+                // Dedupe numbers to make part2 a little easier
+                if seen.contains(id) {
                     continue;
                 }
-                if let Some(elem) = self
-                    .data
-                    .get(dy as usize)
-                    .and_then(|row| row.get(dx as usize))
-                {
-                    if let Elem::Number(id, _val) = elem {
-                        // Dedupe numbers to make part2 a little easier
-                        if seen.contains(id) {
-                            continue;
-                        }
-                        seen[((x - dx + 1) * 3 + (y - dy + 1)) as usize] = *id;
-                    }
-                    neighbors.push(elem);
-                }
+                seen[idx] = *id;
             }
+            neighbors.push(elem);
         }
     }
 }
@@ -94,9 +88,9 @@ pub fn part1(input: &Schematic) -> usize {
     let mut cur_id = 0;
     // A reusable allocation to store neighbors
     let mut neighbors = Vec::<&Elem>::new();
-    for y in 0..input.h {
-        for x in 0..input.w {
-            if let Elem::Number(id, value) = input.data[y][x] {
+    for y in 0..input.data.height() {
+        for x in 0..input.data.width() {
+            if let Elem::Number(id, value) = input.data[(x, y)] {
                 if cur_id < id {
                     // update the neighbors vec
                     input.get_neighbors(&mut neighbors, x, y);
@@ -111,20 +105,20 @@ pub fn part1(input: &Schematic) -> usize {
     res
 }
 
-pub fn part2(input: &Schematic) -> u32 {
+pub fn part2(input: &Schematic) -> u64 {
     let mut res = 0;
     let mut neighbors = Vec::new();
-    for y in 0..input.h {
-        for x in 0..input.w {
-            if let Elem::Symbol('*') = &input.data[y][x] {
+    for y in 0..input.data.height() {
+        for x in 0..input.data.width() {
+            if let Elem::Symbol('*') = &input.data[(x, y)] {
                 input.get_neighbors(&mut neighbors, x, y);
                 let number_neighbors = neighbors
                     .iter()
                     .filter_map(|elem| match elem {
-                        Elem::Number(_id, val) => Some(*val),
+                        Elem::Number(_id, val) => Some(*val as u64),
                         _ => None,
                     })
-                    .collect::<Vec<u32>>();
+                    .collect::<Vec<u64>>();
                 if number_neighbors.len() == 2 {
                     res += number_neighbors[0] * number_neighbors[1]
                 }
@@ -134,8 +128,32 @@ pub fn part2(input: &Schematic) -> u32 {
     res
 }
 
-pub fn read_input(filename: &str) -> Schematic {
-    let file = File::open(filename).expect("File not found");
-    let reader = BufReader::new(file);
-    Schematic::from_vec(&reader.lines().flatten().collect()).expect("failed to parse schematic")
+pub fn parse(data: &str) -> Result<Schematic, ParseError> {
+    let grid = Grid::parse(data, "an ASCII character", |c| {
+        c.is_ascii_graphic().then_some(c as u8)
+    })?;
+    Schematic::from_grid(&grid)
+}
+
+#[cfg(feature = "std")]
+pub fn read_input(filename: &str) -> Result<Schematic, common::InputError> {
+    Day03::read_input(filename)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
 }
@@ -1,10 +1,7 @@
-use day03::{part1, part2, read_input};
+use day03::Day03;
 
-fn main() {
-    let input = read_input("input.txt");
-    let res = part1(&input);
-    println!("Part 1: {}", res); // 539713 (42.274 µs)
-
-    let res = part2(&input);
-    println!("Part 2: {}", res); // 84159075 (17.419 µs)
+// Usage: cargo run --release -- [--format text|json] [INPUT]
+// INPUT defaults to input.txt, and "-" reads stdin
+fn main() -> std::process::ExitCode {
+    common::cli::main::<Day03>(env!("CARGO_PKG_NAME"))
 }
@@ -0,0 +1,6 @@
+use day03::Day03;
+
+#[test]
+fn examples() {
+    common::fixtures::check::<Day03>(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+}
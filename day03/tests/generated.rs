@@ -0,0 +1,6 @@
+use day03::Day03;
+
+#[test]
+fn generated() {
+    common::generate::check::<Day03>();
+}
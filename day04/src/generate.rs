@@ -0,0 +1,57 @@
+//! Random scratchcards: `size` cards of 10 winning numbers and 25 numbers you have.
+
+use common::{Generate, Rng};
+
+use crate::Day04;
+
+/// Part 2 keeps a count of every card. Winning cards multiply the counts of the ones after them,
+/// so cards are only allowed to win as long as the total stays below this, which is about what
+/// the real input ends up with.
+const MAX_CARDS: usize = 1 << 24;
+
+fn numbers(numbers: &[usize]) -> String {
+    let numbers: Vec<String> = numbers.iter().map(|n| format!("{:>2}", n)).collect();
+    numbers.join(" ")
+}
+
+impl Generate for Day04 {
+    const DEFAULT_SIZE: usize = 200;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let width = size.to_string().len();
+        let mut counts = vec![1usize; size];
+        let mut total = size;
+
+        let mut out = String::new();
+        for card in 0..size {
+            let mut matching = rng.range(0..=10);
+            while matching > 0 && total + counts[card] * matching > MAX_CARDS {
+                matching -= 1;
+            }
+            let (copies, end) = (counts[card], (card + 1 + matching).min(size));
+            for count in &mut counts[card + 1..end] {
+                *count += copies;
+            }
+            total += copies * (end - card - 1);
+
+            // The first 10 are the winning numbers, and the next 25 - `matching` are numbers
+            // you have that don't win
+            let mut pool: Vec<usize> = (1..100).collect();
+            rng.shuffle(&mut pool);
+            let winning = &pool[..10];
+            let mut mine = pool[10..35 - matching].to_vec();
+            mine.extend_from_slice(&winning[..matching]);
+            rng.shuffle(&mut mine);
+
+            out.push_str(&format!(
+                "Card {:>width$}: {} | {}\n",
+                card + 1,
+                numbers(winning),
+                numbers(&mine),
+                width = width
+            ));
+        }
+        out
+    }
+}
@@ -1,6 +1,8 @@
+use common::parse::{lines, Line};
+use common::{Answer, ParseError, Solution};
 use std::collections::BTreeSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+
+mod generate;
 
 #[derive(Debug, Clone)]
 pub struct ScratchTicket {
@@ -8,26 +10,26 @@ pub struct ScratchTicket {
     mine: BTreeSet<u8>,
 }
 
-impl From<&str> for ScratchTicket {
-    fn from(line: &str) -> Self {
-        let (_id, numbers) = line.split_once(": ").unwrap();
+impl ScratchTicket {
+    fn from_line(line: &Line) -> Result<Self, ParseError> {
+        let (_id, numbers) = line.split_once(line.text, ": ")?;
 
-        let (winning, mine) = numbers.split_once(" | ").unwrap();
-        let winning = winning
-            .split_whitespace()
-            .map(|x| x.parse().unwrap())
+        let (winning, mine) = line.split_once(numbers, " | ")?;
+        let winning = line
+            .parse_all::<u8>(winning, "a winning number")?
+            .into_iter()
             .collect();
 
-        let mine = mine
-            .split_whitespace()
-            .map(|x| x.parse().unwrap())
+        let mine = line
+            .parse_all::<u8>(mine, "a number")?
+            .into_iter()
             .collect();
 
-        ScratchTicket { winning, mine }
+        Ok(ScratchTicket { winning, mine })
     }
 }
 
-pub fn part1(input: &Vec<ScratchTicket>) -> usize {
+pub fn part1(input: &[ScratchTicket]) -> usize {
     let mut res = 0usize;
     for ticket in input {
         let matching = ticket.mine.intersection(&ticket.winning).count();
@@ -38,7 +40,7 @@ pub fn part1(input: &Vec<ScratchTicket>) -> usize {
     res
 }
 
-pub fn part2(input: &Vec<ScratchTicket>) -> usize {
+pub fn part2(input: &[ScratchTicket]) -> usize {
     let mut quantities = vec![1usize; input.len()];
     for (idx, ticket) in input.iter().enumerate() {
         let matching = ticket.mine.intersection(&ticket.winning).count();
@@ -46,19 +48,38 @@ pub fn part2(input: &Vec<ScratchTicket>) -> usize {
 
         let start = idx + 1;
         let end = usize::min(start + matching, input.len());
-        for i in start..end {
-            quantities[i] += instances;
+        for quantity in &mut quantities[start..end] {
+            *quantity += instances;
         }
     }
     quantities.iter().sum()
 }
 
-pub fn read_input(filename: &str) -> Vec<ScratchTicket> {
-    let file = File::open(filename).expect("File not found");
-    let reader = BufReader::new(file);
-    reader
-        .lines()
-        .flatten()
-        .map(|x| x.as_str().into())
+pub fn parse(data: &str) -> Result<Vec<ScratchTicket>, ParseError> {
+    lines(data)
+        .map(|line| ScratchTicket::from_line(&line))
         .collect()
 }
+
+#[cfg(feature = "std")]
+pub fn read_input(filename: &str) -> Result<Vec<ScratchTicket>, common::InputError> {
+    Day04::read_input(filename)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<ScratchTicket>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
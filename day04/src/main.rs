@@ -1,10 +1,7 @@
-use day04::{part1, part2, read_input};
+use day04::Day04;
 
-fn main() {
-    let input = read_input("input.txt"); // (135.94 µs)
-    let res = part1(&input);
-    println!("Part 1: {}", res); // 18519 (14.132 µs)
-
-    let res = part2(&input);
-    println!("Part 2: {}", res); // 11787590 (14.360 µs)
+// Usage: cargo run --release -- [--format text|json] [INPUT]
+// INPUT defaults to input.txt, and "-" reads stdin
+fn main() -> std::process::ExitCode {
+    common::cli::main::<Day04>(env!("CARGO_PKG_NAME"))
 }
@@ -0,0 +1,6 @@
+use day04::Day04;
+
+#[test]
+fn examples() {
+    common::fixtures::check::<Day04>(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+}
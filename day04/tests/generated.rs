@@ -0,0 +1,6 @@
+use day04::Day04;
+
+#[test]
+fn generated() {
+    common::generate::check::<Day04>();
+}
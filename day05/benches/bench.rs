@@ -1,14 +1,20 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use day05::{part1, part2, read_input};
+use day05::{parse, part1, part1_ranges, part2};
 
 fn criterion_benchmark(c: &mut Criterion) {
-    c.bench_function("reading/parsing", |b| {
-        b.iter(|| read_input(black_box("input.txt")))
-    });
+    // Group by day, so that every crate's results end up in their own directory
+    let mut group = c.benchmark_group("day05");
+
+    let data = std::fs::read_to_string("input.txt").unwrap();
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(&data))));
 
-    let input = read_input("input.txt");
-    c.bench_function("part1", |b| b.iter(|| part1(black_box(&input))));
-    c.bench_function("part2", |b| b.iter(|| part2(black_box(&input))));
+    let input = parse(&data).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&input))));
+    group.bench_function("part1_ranges", |b| {
+        b.iter(|| part1_ranges(black_box(&input)))
+    });
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&input))));
+    group.finish();
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -0,0 +1,62 @@
+//! Random almanacs: seven maps of `size` ranges each, which shuffle blocks of the numbers below
+//! 2^32 around, and `size / 3` seed ranges.
+
+use common::{Generate, Rng};
+
+use crate::Day05;
+
+const STAGES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+const LIMIT: u64 = 1 << 32;
+
+impl Generate for Day05 {
+    const DEFAULT_SIZE: usize = 35;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // At least two blocks, as the lengths have to fit in 32 bits as well
+        let size = size.max(2);
+
+        let seeds: Vec<String> = (0..(size / 3).max(1))
+            .map(|_| {
+                let start = rng.below_u64(LIMIT);
+                let length = 1 + rng.below_u64((LIMIT - start).min(LIMIT / 16));
+                format!("{} {}", start, length)
+            })
+            .collect();
+        let mut out = format!("seeds: {}\n", seeds.join(" "));
+
+        for stage in STAGES.windows(2) {
+            // Cut the numbers into `size` blocks, and lay them out again in a random order
+            let mut cuts: Vec<u64> = (1..size).map(|_| 1 + rng.below_u64(LIMIT - 1)).collect();
+            cuts.extend([0, LIMIT]);
+            cuts.sort_unstable();
+            cuts.dedup();
+            let mut blocks: Vec<(u64, u64)> =
+                cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+            rng.shuffle(&mut blocks);
+
+            out.push_str(&format!("\n{}-to-{} map:\n", stage[0], stage[1]));
+            let mut dest = 0;
+            let mut ranges = Vec::new();
+            for (source, length) in blocks {
+                ranges.push(format!("{} {} {}", dest, source, length));
+                dest += length;
+            }
+            rng.shuffle(&mut ranges);
+            for range in ranges {
+                out.push_str(&range);
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
@@ -1,76 +1,95 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use common::parse::sections;
+use common::{Answer, ParseError, Solution, Variant};
+use std::str::FromStr;
+use tracing::{debug, trace};
+
+mod generate;
 
 #[derive(Debug, Clone, Default)]
 pub struct Mapping {
-    source: usize,
-    dest: usize,
-    length: usize,
+    source: u64,
+    dest: u64,
+    length: u64,
 }
 
 impl Mapping {
-    fn end(&self) -> usize {
+    fn end(&self) -> u64 {
         self.source + self.length
     }
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct PuzzleInput {
-    seeds: Vec<usize>,
+    seeds: Vec<u64>,
     mappings: Vec<Vec<Mapping>>,
 }
 
-impl PuzzleInput {
-    fn from_vec(input: &Vec<String>) -> Option<Self> {
-        let seeds: Vec<usize> = input
-            .get(0)?
-            .split_once("seeds: ")?
-            .1
-            .split_whitespace()
-            .map(|x| x.parse().unwrap())
-            .collect();
+impl FromStr for PuzzleInput {
+    type Err = ParseError;
 
-        let mut i = 3;
-        let mut mappings = Vec::new();
-        let mut ranges = Vec::new();
-        while i < input.len() {
-            let line = input.get(i)?;
-
-            if line.is_empty() {
-                i += 2;
-                mappings.push(ranges.clone());
-                ranges.clear();
-                continue;
-            }
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let sections = sections(input);
+        let mut sections = sections.iter();
 
-            let mut splitter = line.split_whitespace();
-            let dest: usize = splitter.next()?.parse().ok()?;
-            let source: usize = splitter.next()?.parse().ok()?;
-            let length: usize = splitter.next()?.parse().ok()?;
+        // The first section is the single line of seeds
+        let seed_section = sections
+            .next()
+            .ok_or_else(|| ParseError::new(1, 1, "", "expected a 'seeds: ' line"))?;
+        let line = seed_section[0];
+        // All numbers fit in 32 bits, so adding two of them can't overflow a u64
+        let seeds: Vec<u32> = line.parse_all(
+            line.strip_prefix(line.text, "seeds: ")?,
+            "a 32-bit seed number",
+        )?;
+        let seeds: Vec<u64> = seeds.into_iter().map(u64::from).collect();
+        if seeds.is_empty() {
+            return Err(line.error_at_end("expected at least one seed"));
+        }
+        if !seeds.len().is_multiple_of(2) {
+            return Err(line.error_at_end("expected seeds to come in (start, length) pairs"));
+        }
+        if let Some(line) = seed_section.get(1) {
+            return Err(line.error(line.text, "expected an empty line after the seeds"));
+        }
 
-            ranges.push(Mapping {
-                source,
-                dest,
-                length,
-            });
+        // Every following section is a "x-to-y map:" header, followed by the ranges
+        let mut mappings = Vec::new();
+        for section in sections {
+            let header = section[0];
+            if !header.text.ends_with(" map:") {
+                return Err(header.error(header.text, "expected a 'x-to-y map:' header"));
+            }
 
-            i += 1;
+            let ranges = section[1..]
+                .iter()
+                .map(|line| {
+                    let numbers: Vec<u32> = line.parse_all(line.text, "a 32-bit number")?;
+                    match numbers[..] {
+                        [dest, source, length] => Ok(Mapping {
+                            source: source as u64,
+                            dest: dest as u64,
+                            length: length as u64,
+                        }),
+                        _ => {
+                            Err(line.error(line.text, "expected a destination, source and length"))
+                        }
+                    }
+                })
+                .collect::<Result<Vec<Mapping>, ParseError>>()?;
+            mappings.push(ranges);
         }
 
-        // Add the last range vec
-        mappings.push(ranges);
-
-        Some(PuzzleInput { seeds, mappings })
+        Ok(PuzzleInput { seeds, mappings })
     }
 }
 
-pub fn part1(input: &PuzzleInput) -> usize {
-    let mut lowest = usize::MAX;
+pub fn part1(input: &PuzzleInput) -> u64 {
+    let mut lowest = u64::MAX;
     for seed in &input.seeds {
         let mut translated = *seed;
         for stage in &input.mappings {
             for mapping in stage {
-                if translated > mapping.source && translated < mapping.end() {
+                if translated >= mapping.source && translated < mapping.end() {
                     let new = mapping.dest + (translated - mapping.source);
                     translated = new;
                     break;
@@ -86,8 +105,8 @@ pub fn part1(input: &PuzzleInput) -> usize {
 
 #[derive(Debug, Clone, Default)]
 struct Range {
-    start: usize,
-    length: usize,
+    start: u64,
+    length: u64,
 }
 
 impl Range {
@@ -95,7 +114,7 @@ impl Range {
         range.source < self.start + self.length && self.start < range.source + range.length
     }
 
-    fn end(&self) -> usize {
+    fn end(&self) -> u64 {
         self.start + self.length
     }
 
@@ -105,8 +124,8 @@ impl Range {
             return None;
         }
 
-        let start = usize::max(self.start, range.source);
-        let end = usize::min(self.start + self.length, range.end());
+        let start = u64::max(self.start, range.source);
+        let end = u64::min(self.start + self.length, range.end());
         let overlap = Range {
             start,
             length: end - start,
@@ -143,11 +162,11 @@ impl Range {
         let mut todo = Vec::new();
         todo.push(self.clone());
 
-        'outer: while !todo.is_empty() {
-            let x = todo.pop().unwrap();
+        'outer: while let Some(x) = todo.pop() {
             for mapping in mappings {
                 // Does this mapping apply?
                 if let Some(parts) = x.overlap(mapping) {
+                    trace!(range = ?x, ?parts, "split");
                     translated.push(parts.1.translate(mapping));
                     if let Some(before) = parts.0 {
                         todo.push(before);
@@ -166,22 +185,15 @@ impl Range {
     }
 }
 
-pub fn part2(input: &PuzzleInput) -> usize {
-    let seed_ranges: Vec<Range> = input
-        .seeds
-        .chunks(2)
-        .map(|x| Range {
-            start: x[0],
-            length: x[1],
-        })
-        .collect();
-
+/// Translates the seed ranges through every stage, and returns the lowest location they end up at.
+fn lowest_location(seed_ranges: Vec<Range>, input: &PuzzleInput) -> u64 {
     let mut translated_ranges = seed_ranges;
-    for mapping in &input.mappings {
+    for (stage, mapping) in input.mappings.iter().enumerate() {
         translated_ranges = translated_ranges
             .iter()
             .flat_map(|range| range.split_translate(mapping))
             .collect();
+        debug!(stage, ranges = translated_ranges.len(), "translated");
     }
 
     translated_ranges
@@ -191,8 +203,60 @@ pub fn part2(input: &PuzzleInput) -> usize {
         .unwrap()
 }
 
-pub fn read_input(filename: &str) -> PuzzleInput {
-    let file = File::open(filename).expect("File not found");
-    let reader = BufReader::new(file);
-    PuzzleInput::from_vec(&reader.lines().flatten().collect()).expect("failed to parse")
+/// Part 1 the way part 2 does it, with every seed a range of its own.
+pub fn part1_ranges(input: &PuzzleInput) -> u64 {
+    let seed_ranges = input
+        .seeds
+        .iter()
+        .map(|&start| Range { start, length: 1 })
+        .collect();
+    lowest_location(seed_ranges, input)
+}
+
+pub fn part2(input: &PuzzleInput) -> u64 {
+    let seed_ranges: Vec<Range> = input
+        .seeds
+        .chunks(2)
+        .map(|x| Range {
+            start: x[0],
+            length: x[1],
+        })
+        .collect();
+
+    lowest_location(seed_ranges, input)
+}
+
+pub fn parse(data: &str) -> Result<PuzzleInput, ParseError> {
+    data.parse()
+}
+
+#[cfg(feature = "std")]
+pub fn read_input(filename: &str) -> Result<PuzzleInput, common::InputError> {
+    Day05::read_input(filename)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![Variant {
+            name: "ranges",
+            part: 1,
+            solve: |input| part1_ranges(input).into(),
+        }]
+    }
 }
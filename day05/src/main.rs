@@ -1,10 +1,7 @@
-use day05::{part1, part2, read_input};
+use day05::Day05;
 
-fn main() {
-    let input = read_input("input.txt"); // (21.172 µs)
-    let res = part1(&input);
-    println!("Part 1: {}", res); // 486613012 (660.14 ns)
-
-    let res = part2(&input);
-    println!("Part 2: {}", res); // 56931769 (10.715 µs)
+// Usage: cargo run --release -- [--format text|json] [INPUT]
+// INPUT defaults to input.txt, and "-" reads stdin
+fn main() -> std::process::ExitCode {
+    common::cli::main::<Day05>(env!("CARGO_PKG_NAME"))
 }
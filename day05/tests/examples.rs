@@ -0,0 +1,6 @@
+use day05::Day05;
+
+#[test]
+fn examples() {
+    common::fixtures::check::<Day05>(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+}
@@ -0,0 +1,6 @@
+use day05::Day05;
+
+#[test]
+fn generated() {
+    common::generate::check::<Day05>();
+}
@@ -0,0 +1,36 @@
+use common::Rng;
+use day05::Day05;
+
+#[test]
+fn variants() {
+    common::variants::check::<Day05>();
+}
+
+/// A small almanac whose maps leave gaps between their ranges, which the generated ones never do,
+/// so that some numbers map to themselves.
+fn almanac_with_gaps(rng: &mut Rng) -> String {
+    let seeds: Vec<String> = (0..rng.range(1..=4) * 2)
+        .map(|_| rng.below(100).to_string())
+        .collect();
+    let mut out = format!("seeds: {}\n", seeds.join(" "));
+    for stage in 0..rng.range(1..=4) {
+        out.push_str(&format!("\n{}-to-{} map:\n", stage, stage + 1));
+        let mut cuts: Vec<usize> = (0..rng.range(1..=8)).map(|_| rng.below(100)).collect();
+        cuts.sort_unstable();
+        cuts.dedup();
+        for block in cuts.windows(2) {
+            if rng.chance(0.6) {
+                let (source, length) = (block[0], block[1] - block[0]);
+                out.push_str(&format!("{} {} {}\n", rng.below(100), source, length));
+            }
+        }
+    }
+    out
+}
+
+#[test]
+fn gaps() {
+    let mut rng = Rng::new(5);
+    let almanacs: Vec<String> = (0..1000).map(|_| almanac_with_gaps(&mut rng)).collect();
+    common::variants::check_inputs::<Day05>(almanacs);
+}
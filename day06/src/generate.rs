@@ -0,0 +1,24 @@
+//! Random race records: `size` races, at most 4. Part 2 glues all of them into a single race, and
+//! any more would no longer fit in an `i64`.
+
+use common::{Generate, Rng};
+
+use crate::Day06;
+
+impl Generate for Day06 {
+    const DEFAULT_SIZE: usize = 4;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut times = String::from("Time:    ");
+        let mut distances = String::from("Distance:");
+        for _ in 0..size.clamp(1, 4) {
+            // The record is somewhere in the top half of what's possible, and can always be beaten
+            let time = rng.range(7..=99);
+            let best = time * time / 4;
+            let distance = rng.range(best / 2..best);
+            times.push_str(&format!(" {:>6}", time));
+            distances.push_str(&format!(" {:>6}", distance));
+        }
+        format!("{}\n{}\n", times, distances)
+    }
+}
@@ -1,7 +1,15 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use common::parse::{lines, Line};
+use common::{Answer, ParseError, Solution};
 
-fn get_intersections(m: isize, d: isize) -> (f64, f64) {
+mod generate;
+
+#[derive(Debug, Clone)]
+pub struct PuzzleInput {
+    times: Vec<i64>,
+    distances: Vec<i64>,
+}
+
+fn get_intersections(m: i64, d: i64) -> (f64, f64) {
     // -X^2 + mx - d
     let a = -1f64;
     let b = m as f64;
@@ -13,62 +21,101 @@ fn get_intersections(m: isize, d: isize) -> (f64, f64) {
     (x1, x2)
 }
 
-fn get_ways(t: isize, d: isize) -> isize {
+fn get_ways(t: i64, d: i64) -> i64 {
     let (x1, x2) = get_intersections(t, d);
     let x1 = match x1.fract() == 0.0 {
-        true => x1 as isize + 1,
-        false => x1.ceil() as isize,
+        true => x1 as i64 + 1,
+        false => x1.ceil() as i64,
     };
     let x2 = match x2.fract() == 0.0 {
-        true => x2 as isize - 1,
-        false => x2.floor() as isize,
+        true => x2 as i64 - 1,
+        false => x2.floor() as i64,
     };
-    x2 - x1 + 1
+    // A record that can't be beaten has no real intersections at all
+    (x2 - x1 + 1).max(0)
 }
 
-pub fn part1(input: &[String]) -> isize {
-    let times: Vec<isize> = input[0]
-        .strip_prefix("Time:")
-        .unwrap()
-        .split_whitespace()
-        .map(|x| x.parse().unwrap())
-        .collect();
-    let distances: Vec<isize> = input[1]
-        .strip_prefix("Distance:")
-        .unwrap()
-        .split_whitespace()
-        .map(|x| x.parse().unwrap())
-        .collect();
+pub fn part1(input: &PuzzleInput) -> i64 {
     let mut ret = 1;
 
-    for i in 0..times.len() {
-        let t = times[i];
-        let d = distances[i];
+    for (&t, &d) in input.times.iter().zip(&input.distances) {
         ret *= get_ways(t, d);
     }
 
     ret
 }
 
-pub fn part2(input: &[String]) -> isize {
-    let t: isize = input[0]
-        .strip_prefix("Time:")
-        .unwrap()
-        .replace(' ', "")
-        .parse()
-        .unwrap();
-    let d: isize = input[1]
-        .strip_prefix("Distance:")
-        .unwrap()
-        .replace(' ', "")
-        .parse()
-        .unwrap();
+/// Glue the digits of all numbers together, ignoring the "kerning". `None` if that doesn't fit.
+fn concat(numbers: &[i64]) -> Option<i64> {
+    numbers.iter().try_fold(0i64, |acc, &x| {
+        let shift = 10i64.checked_pow(x.checked_ilog10().unwrap_or(0) + 1)?;
+        acc.checked_mul(shift)?.checked_add(x)
+    })
+}
+
+pub fn part2(input: &PuzzleInput) -> i64 {
+    let t = concat(&input.times).expect("checked when parsing");
+    let d = concat(&input.distances).expect("checked when parsing");
 
     get_ways(t, d)
 }
 
-pub fn read_input(filename: &str) -> Vec<String> {
-    let file = File::open(filename).expect("File not found");
-    let reader = BufReader::new(file);
-    reader.lines().flatten().collect()
+fn parse_row(lines: &[Line], idx: usize, prefix: &str) -> Result<Vec<i64>, ParseError> {
+    let line = lines
+        .get(idx)
+        .ok_or_else(|| ParseError::new(idx + 1, 1, "", format!("expected a '{}' line", prefix)))?;
+    let numbers = line.strip_prefix(line.text, prefix)?;
+    let row = numbers
+        .split_whitespace()
+        .map(|token| match line.parse(token, "a number")? {
+            n if n < 0 => Err(line.error(token, "expected a non-negative number")),
+            n => Ok(n),
+        })
+        .collect::<Result<Vec<i64>, ParseError>>()?;
+
+    // Part 2 reads the whole row as a single number. A product is never larger than the numbers
+    // glued together, so this keeps part 1 from overflowing as well.
+    match concat(&row) {
+        Some(_) => Ok(row),
+        None => Err(line.error(
+            numbers,
+            "expected the numbers to fit in 64 bits when combined",
+        )),
+    }
+}
+
+pub fn parse(data: &str) -> Result<PuzzleInput, ParseError> {
+    let lines: Vec<Line> = lines(data).collect();
+    let times = parse_row(&lines, 0, "Time:")?;
+    let distances = parse_row(&lines, 1, "Distance:")?;
+
+    if times.len() != distances.len() {
+        let message = format!("expected {} distances, one for every race", times.len());
+        return Err(lines[1].error_at_end(message));
+    }
+
+    Ok(PuzzleInput { times, distances })
+}
+
+#[cfg(feature = "std")]
+pub fn read_input(filename: &str) -> Result<PuzzleInput, common::InputError> {
+    Day06::read_input(filename)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
 }
@@ -1,10 +1,7 @@
-use day06::{part1, part2, read_input};
+use day06::Day06;
 
-fn main() {
-    let input = read_input("input.txt"); // (3.5390 µs)
-    let res = part1(&input);
-    println!("Part 1: {}", res); //  (124.45 ns)
-
-    let res = part2(&input);
-    println!("Part 2: {}", res); //  (190.15 ns)
+// Usage: cargo run --release -- [--format text|json] [INPUT]
+// INPUT defaults to input.txt, and "-" reads stdin
+fn main() -> std::process::ExitCode {
+    common::cli::main::<Day06>(env!("CARGO_PKG_NAME"))
 }
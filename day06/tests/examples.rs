@@ -0,0 +1,6 @@
+use day06::Day06;
+
+#[test]
+fn examples() {
+    common::fixtures::check::<Day06>(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+}
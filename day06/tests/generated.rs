@@ -0,0 +1,6 @@
+use day06::Day06;
+
+#[test]
+fn generated() {
+    common::generate::check::<Day06>();
+}
@@ -1,14 +1,17 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use day07::{part1, part2, read_input};
+use day07::{parse, part1, part2};
 
 fn criterion_benchmark(c: &mut Criterion) {
-    c.bench_function("reading/parsing", |b| {
-        b.iter(|| read_input(black_box("input.txt")))
-    });
+    // Group by day, so that every crate's results end up in their own directory
+    let mut group = c.benchmark_group("day07");
 
-    let input = read_input("input.txt");
-    c.bench_function("part1", |b| b.iter(|| part1(black_box(&input))));
-    c.bench_function("part2", |b| b.iter(|| part2(black_box(&input))));
+    let data = std::fs::read_to_string("input.txt").unwrap();
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(&data))));
+
+    let input = parse(&data).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&input))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&input))));
+    group.finish();
 }
 
 criterion_group!(benches, criterion_benchmark);
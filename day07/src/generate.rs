@@ -0,0 +1,19 @@
+//! Random Camel Cards hands: `size` hands of 5 cards, each with a bid.
+
+use common::{Generate, Rng};
+
+use crate::{Day07, CARDS};
+
+impl Generate for Day07 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let cards: Vec<char> = CARDS.chars().collect();
+        let mut out = String::new();
+        for _ in 0..size.max(1) {
+            let hand: String = (0..5).map(|_| *rng.pick(&cards)).collect();
+            out.push_str(&format!("{} {}\n", hand, rng.range(1..=1000)));
+        }
+        out
+    }
+}
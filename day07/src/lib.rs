@@ -1,8 +1,15 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use common::parse::lines;
+use common::{Answer, ParseError, Solution};
+
+mod generate;
 
 type Hand = u32;
 
+/// A hand of cards as written in the input, with its bid.
+type PuzzleInput = Vec<(String, u64)>;
+
+const CARDS: &str = "23456789TJQKA";
+
 #[repr(u8)]
 #[derive(Debug)]
 enum HandType {
@@ -78,22 +85,17 @@ fn parse_hand(s: &str, weak_joker: bool) -> Hand {
     (t << 20) | val
 }
 
-pub fn part1(input: &[String]) -> usize {
-    let mut hands: Vec<(Hand, usize)> = input
+pub fn part1(input: &PuzzleInput) -> u64 {
+    let mut hands: Vec<(Hand, u64)> = input
         .iter()
-        .map(|line| {
-            let (hand, bid) = line.split_once(' ').unwrap();
-            let hand = parse_hand(hand, false);
-            let bid = bid.parse().unwrap();
-            (hand, bid)
-        })
+        .map(|(hand, bid)| (parse_hand(hand, false), *bid))
         .collect();
 
     hands.sort_unstable_by_key(|(hand, _bid)| *hand);
     hands
         .iter()
         .enumerate()
-        .map(|(rank, (_hand, bid))| (rank + 1) * bid)
+        .map(|(rank, (_hand, bid))| (rank as u64 + 1) * bid)
         .sum()
 }
 
@@ -143,27 +145,57 @@ fn update_hand(orig_hand: &str) -> Hand {
     (t << 20) | val
 }
 
-pub fn part2(input: &[String]) -> usize {
-    let mut hands: Vec<(Hand, usize)> = input
+pub fn part2(input: &PuzzleInput) -> u64 {
+    let mut hands: Vec<(Hand, u64)> = input
         .iter()
-        .map(|line| {
-            let (hand, bid) = line.split_once(' ').unwrap();
-            let hand = update_hand(hand);
-            let bid = bid.parse().unwrap();
-            (hand, bid)
-        })
+        .map(|(hand, bid)| (update_hand(hand), *bid))
         .collect();
 
     hands.sort_unstable_by_key(|(hand, _bid)| *hand);
     hands
         .iter()
         .enumerate()
-        .map(|(rank, (_hand, bid))| (rank + 1) * bid)
+        .map(|(rank, (_hand, bid))| (rank as u64 + 1) * bid)
         .sum()
 }
 
-pub fn read_input(filename: &str) -> Vec<String> {
-    let file = File::open(filename).expect("File not found");
-    let reader = BufReader::new(file);
-    reader.lines().flatten().collect()
+pub fn parse(data: &str) -> Result<PuzzleInput, ParseError> {
+    lines(data)
+        .map(|line| {
+            let (hand, bid) = line.split_once(line.text, " ")?;
+            if let Some((idx, c)) = hand.char_indices().find(|(_, c)| !CARDS.contains(*c)) {
+                let card = &hand[idx..idx + c.len_utf8()];
+                return Err(line.error(card, format!("expected one of '{}'", CARDS)));
+            }
+            if hand.len() != 5 {
+                return Err(line.error(hand, "expected a hand of 5 cards"));
+            }
+            // Small enough that the sum of (rank * bid) fits a u64 for up to 23 million hands
+            let bid: u16 = line.parse(bid, "a bid below 65536")?;
+            Ok((hand.to_string(), bid as u64))
+        })
+        .collect()
+}
+
+#[cfg(feature = "std")]
+pub fn read_input(filename: &str) -> Result<PuzzleInput, common::InputError> {
+    Day07::read_input(filename)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
 }
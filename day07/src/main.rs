@@ -1,10 +1,7 @@
-use day07::{part1, part2, read_input};
+use day07::Day07;
 
-fn main() {
-    let input = read_input("input.txt"); // (43.386 µs)
-    let res = part1(&input);
-    println!("Part 1: {}", res); // 249483956 (43.683 µs)
-
-    let res = part2(&input);
-    println!("Part 2: {}", res); // 252137472 (48.028 µs)
+// Usage: cargo run --release -- [--format text|json] [INPUT]
+// INPUT defaults to input.txt, and "-" reads stdin
+fn main() -> std::process::ExitCode {
+    common::cli::main::<Day07>(env!("CARGO_PKG_NAME"))
 }
@@ -0,0 +1,6 @@
+use day07::Day07;
+
+#[test]
+fn examples() {
+    common::fixtures::check::<Day07>(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+}
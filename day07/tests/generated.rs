@@ -0,0 +1,6 @@
+use day07::Day07;
+
+#[test]
+fn generated() {
+    common::generate::check::<Day07>();
+}
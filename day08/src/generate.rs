@@ -0,0 +1,85 @@
+//! Random maps: a line of directions, and a loop for every ghost (with `AAA` to `ZZZ` as the
+//! first one). `size` is roughly the number of nodes.
+//!
+//! Like in the real puzzle, every ghost first reaches its end node after as many steps as it
+//! takes to get back there, which is what part 2 relies on. Each step along a loop has two nodes
+//! that both lead to the next step, so whichever way the directions go, the ghost stays on it.
+
+use std::collections::HashSet;
+
+use common::{Generate, Rng};
+
+use crate::Day08;
+
+const SYMBOLS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+/// What labels that are neither start nor end nodes end with
+const OTHER: &[u8] = b"BCDEFGHIJKLMNOPQRSTUVWXY0123456789";
+
+/// Part 2 is the least common multiple of the loop lengths, which has to fit in an `i64` for 6
+/// ghosts.
+const MAX_LOOP: usize = 1000;
+
+/// A label that isn't in use yet, ending in `last`.
+fn label(rng: &mut Rng, used: &mut HashSet<String>, last: u8) -> String {
+    loop {
+        let label = [*rng.pick(SYMBOLS), *rng.pick(SYMBOLS), last];
+        let label = String::from_utf8(label.to_vec()).unwrap();
+        if used.insert(label.clone()) {
+            return label;
+        }
+    }
+}
+
+/// Adds a node leading to both of `to`, in a random order.
+fn link(rng: &mut Rng, nodes: &mut Vec<String>, from: &str, to: &[String; 2]) {
+    let mut to = to.clone();
+    rng.shuffle(&mut to);
+    nodes.push(format!("{} = ({}, {})", from, to[0], to[1]));
+}
+
+impl Generate for Day08 {
+    const DEFAULT_SIZE: usize = 750;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let ghosts = (size / 64).clamp(1, 6);
+        let length = (size / (2 * ghosts)).clamp(2, MAX_LOOP);
+
+        let directions: String = (0..rng.range(1..=size / 2 + 1))
+            .map(|_| *rng.pick(&['L', 'R']))
+            .collect();
+
+        let mut used = HashSet::from(["AAA".to_string(), "ZZZ".to_string()]);
+        let mut nodes = Vec::new();
+        for ghost in 0..ghosts {
+            let (start, end) = match ghost {
+                0 => ("AAA".to_string(), "ZZZ".to_string()),
+                _ => (label(rng, &mut used, b'A'), label(rng, &mut used, b'Z')),
+            };
+            // The start and end node are step 0, and the others come in pairs
+            let steps = rng.range(length / 2 + 1..=length);
+            let pairs: Vec<[String; 2]> = (1..steps)
+                .map(|_| {
+                    let mut other = || {
+                        let last = *rng.pick(OTHER);
+                        label(rng, &mut used, last)
+                    };
+                    [other(), other()]
+                })
+                .collect();
+
+            link(rng, &mut nodes, &start, &pairs[0]);
+            link(rng, &mut nodes, &end, &pairs[0]);
+            for (pair, next) in pairs.iter().zip(&pairs[1..]) {
+                link(rng, &mut nodes, &pair[0], next);
+                link(rng, &mut nodes, &pair[1], next);
+            }
+            let last = &pairs[pairs.len() - 1];
+            let end = [end.clone(), end];
+            link(rng, &mut nodes, &last[0], &end);
+            link(rng, &mut nodes, &last[1], &end);
+        }
+        rng.shuffle(&mut nodes);
+
+        format!("{}\n\n{}\n", directions, nodes.join("\n"))
+    }
+}
@@ -1,5 +1,9 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use common::parse::{lines, Line};
+use common::{Answer, ParseError, Solution};
+use math::{crt, Congruence};
+use tracing::debug;
+
+mod generate;
 
 type Label = u16;
 
@@ -21,51 +25,66 @@ pub struct PuzzleInput {
     nodes: Vec<Label>,
 }
 
-// https://rosettacode.org/wiki/Least_common_multiple#Rust
-use std::cmp::{max, min};
-
-fn gcd(a: usize, b: usize) -> usize {
-    match ((a, b), (a & 1, b & 1)) {
-        ((x, y), _) if x == y => y,
-        ((0, x), _) | ((x, 0), _) => x,
-        ((x, y), (0, 1)) | ((y, x), (1, 0)) => gcd(x >> 1, y),
-        ((x, y), (0, 0)) => gcd(x >> 1, y >> 1) << 1,
-        ((x, y), (1, 1)) => {
-            let (x, y) = (min(x, y), max(x, y));
-            gcd((y - x) >> 1, x)
-        }
-        _ => unreachable!(),
-    }
-}
+// Labels are made up of digits and uppercase letters, so each character is a base 36 digit.
+// 36^3 still fits in a u16.
+const BASE: Label = 36;
 
-fn lcm(a: usize, b: &usize) -> usize {
-    a * b / gcd(a, *b)
+#[inline(always)]
+fn digit(b: u8) -> Label {
+    match b {
+        b'0'..=b'9' => (b - b'0') as Label,
+        _ => (b - b'A') as Label + 10,
+    }
 }
 
 #[inline(always)]
 fn key_from_str(s: &str) -> Label {
     s.bytes()
-        .enumerate()
         .take(3)
-        .map(|(i, b)| ((b - b'A') as Label) << (i * 5))
-        .sum()
+        .fold((0, 1), |(key, weight), b| {
+            (key + digit(b) * weight, weight * BASE)
+        })
+        .0
+}
+
+fn parse_label<'a>(line: &Line<'a>, s: &'a str) -> Result<Label, ParseError> {
+    match s.len() == 3
+        && s.bytes()
+            .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+    {
+        true => Ok(key_from_str(s)),
+        false => Err(line.error(s, "expected a label of three uppercase letters or digits")),
+    }
 }
 
 #[inline(always)]
 fn is_start_node(l: Label) -> bool {
-    (l >> (2 * 5) & 0b11111) == 0u16
+    l / (BASE * BASE) == digit(b'A')
 }
 
 #[inline(always)]
 fn is_end_node(l: Label) -> bool {
-    (l >> (2 * 5) & 0b11111) == (b'Z' - b'A') as u16
+    l / (BASE * BASE) == digit(b'Z')
 }
 
-pub fn part1(input: &PuzzleInput) -> usize {
+/// How far a walk can get before it must be going round in circles: by then it has been at every
+/// node at every position in the directions.
+fn max_steps(input: &PuzzleInput) -> usize {
+    input.nodes.len() * input.directions.len()
+}
+
+pub fn part1(input: &PuzzleInput) -> Option<usize> {
     let mut cur_pos = key_from_str("AAA");
     let end = key_from_str("ZZZ");
+    // The part 2 example doesn't have an AAA at all
+    if !input.nodes.contains(&cur_pos) {
+        return None;
+    }
     let mut i = 0;
     while cur_pos != end {
+        if i == max_steps(input) {
+            return None;
+        }
         let options = input.map[cur_pos as usize];
         cur_pos = match input.directions[i % input.directions.len()] {
             true => options.left,
@@ -74,58 +93,144 @@ pub fn part1(input: &PuzzleInput) -> usize {
         i += 1;
     }
 
-    i
+    Some(i)
+}
+
+/// Walks from `node` (having taken `i` steps so far) until the next end node, returning it and the
+/// number of steps taken in total. `None` if the walk never gets to one.
+fn walk_to_end(input: &PuzzleInput, mut node: Label, mut i: usize) -> Option<(Label, usize)> {
+    for _ in 0..max_steps(input) {
+        let options = input.map[node as usize];
+        node = match input.directions[i % input.directions.len()] {
+            true => options.left,
+            false => options.right,
+        };
+        i += 1;
+        if is_end_node(node) {
+            return Some((node, i));
+        }
+    }
+    None
 }
 
-pub fn part2(input: &PuzzleInput) -> usize {
-    let mut start_nodes: Vec<Label> = input
+pub fn part2(input: &PuzzleInput) -> Option<u64> {
+    // Each ghost first reaches an end node after `first` steps, and (as the puzzle is built) keeps
+    // reaching one every `cycle` steps after that. They're all at an end node at the same time at
+    // some x ≡ first (mod cycle) for every ghost.
+    let mut latest_first = 0;
+    let congruences: Vec<Congruence<i64>> = input
         .nodes
         .iter()
         .copied()
         .filter(|x| is_start_node(*x))
-        .collect();
-    let cycles: Vec<usize> = start_nodes
-        .iter_mut()
         .map(|node| {
-            let mut i = 0;
-            while !is_end_node(*node) {
-                let options = input.map[*node as usize];
-                *node = match input.directions[i % input.directions.len()] {
-                    true => options.left,
-                    false => options.right,
-                };
-                i += 1;
-            }
-            i
+            let (node, first) = walk_to_end(input, node, 0)?;
+            let (_, second) = walk_to_end(input, node, first)?;
+            debug!(first, cycle = second - first, "ghost reaches an end node");
+            latest_first = latest_first.max(first as i64);
+            Some(Congruence::new(first as i64, (second - first) as i64))
         })
-        .collect();
-
-    let res = cycles.iter().fold(1, lcm);
+        .collect::<Option<_>>()?;
+
+    // No solution if the ghosts never line up (or only after more than 2^63 steps)
+    let all = crt(congruences).ok()?;
+    debug!(
+        residue = all.residue,
+        modulus = all.modulus,
+        "ghosts line up"
+    );
+    // The smallest solution at which every ghost has reached its first end node
+    let mut res = all.residue;
+    if res < latest_first {
+        let cycles = (latest_first - res - 1) / all.modulus + 1;
+        res = res.checked_add(cycles.checked_mul(all.modulus)?)?;
+    }
 
-    res
+    Some(res as u64)
 }
 
-pub fn read_input(filename: &str) -> PuzzleInput {
-    let file = File::open(filename).expect("File not found");
-    let reader = BufReader::new(file);
-    let mut iter = reader.lines().flatten();
-    let directions = iter.next().unwrap().chars().map(|c| c == 'L').collect();
-    iter.next();
+pub fn parse(data: &str) -> Result<PuzzleInput, ParseError> {
+    let mut iter = lines(data);
+
+    let line = iter
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "", "expected a line of directions"))?;
+    let directions = line
+        .text
+        .char_indices()
+        .map(|(idx, c)| match c {
+            'L' => Ok(true),
+            'R' => Ok(false),
+            _ => Err(line.error(&line.text[idx..idx + c.len_utf8()], "expected 'L' or 'R'")),
+        })
+        .collect::<Result<Vec<bool>, ParseError>>()?;
+    if directions.is_empty() {
+        return Err(line.error_at_end("expected a line of directions"));
+    }
+
+    if let Some(line) = iter.next().filter(|line| !line.text.is_empty()) {
+        return Err(line.error(line.text, "expected an empty line"));
+    }
+
     let mut map = vec![Node::default(); 65536];
-    let nodes = iter
+    let mut references = Vec::new();
+    let nodes: Vec<Label> = iter
         .map(|line| {
-            let (key, tuple) = line.split_once(" = ").unwrap();
-            let (left, right) = tuple[1..tuple.len() - 1].split_once(", ").unwrap();
-            let (left, right) = (key_from_str(left), key_from_str(right));
-            let key = key_from_str(key);
+            let (key, tuple) = line.split_once(line.text, " = ")?;
+            let tuple = line.strip_prefix(tuple, "(")?;
+            let tuple = tuple
+                .strip_suffix(')')
+                .ok_or_else(|| line.error_at_end("expected ')'"))?;
+            let (left, right) = line.split_once(tuple, ", ")?;
+            references.extend([(line, left), (line, right)]);
+            let (left, right) = (parse_label(&line, left)?, parse_label(&line, right)?);
+            let key = parse_label(&line, key)?;
             map[key as usize] = Node { left, right };
-            key
+            Ok(key)
         })
-        .collect();
+        .collect::<Result<_, ParseError>>()?;
+
+    // A walk must not be able to step off the map
+    let mut defined = vec![false; map.len()];
+    for &node in &nodes {
+        defined[node as usize] = true;
+    }
+    if let Some((line, label)) = references
+        .into_iter()
+        .find(|(_, label)| !defined[key_from_str(label) as usize])
+    {
+        return Err(line.error(
+            label,
+            "expected a node that is defined on a line of its own",
+        ));
+    }
 
-    PuzzleInput {
+    Ok(PuzzleInput {
         directions,
         nodes,
         map,
+    })
+}
+
+#[cfg(feature = "std")]
+pub fn read_input(filename: &str) -> Result<PuzzleInput, common::InputError> {
+    Day08::read_input(filename)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
     }
 }
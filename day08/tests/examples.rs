@@ -0,0 +1,6 @@
+use day08::Day08;
+
+#[test]
+fn examples() {
+    common::fixtures::check::<Day08>(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+}
@@ -0,0 +1,6 @@
+use day08::Day08;
+
+#[test]
+fn generated() {
+    common::generate::check::<Day08>();
+}
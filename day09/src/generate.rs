@@ -0,0 +1,54 @@
+//! Random OASIS reports: `size` histories, each the values of a polynomial of a low degree.
+
+use common::{Generate, Rng};
+
+use crate::Day09;
+
+/// The values (including the extrapolated ones) and all of their differences have to stay below
+/// this, spread over all histories, for the sums to fit in an `i32`.
+const LIMIT: i64 = i32::MAX as i64;
+
+/// `n` choose `k`, for any integer `n`: the basis the polynomials are built in, which gives
+/// integer values for integer coefficients.
+fn choose(n: i64, k: u32) -> i64 {
+    (0..k as i64).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
+
+impl Generate for Day09 {
+    const DEFAULT_SIZE: usize = 200;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let limit = LIMIT / (2 * size as i64 + 2);
+
+        let mut out = String::new();
+        for _ in 0..size {
+            let length = rng.range(5..=21);
+            let degree = rng.range(0..=6.min(length - 2)) as u32;
+            // Differences grow by up to a factor 2 for every degree, so leave room for those
+            let mut scale = (limit >> (degree + 1)) as usize;
+            let values = loop {
+                let coefficients: Vec<i64> = (0..=degree)
+                    .map(|_| rng.range(0..=2 * scale) as i64 - scale as i64)
+                    .collect();
+                let values: Vec<i64> = (-1..=length as i64)
+                    .map(|x| {
+                        (0..=degree)
+                            .map(|k| coefficients[k as usize] * choose(x, k))
+                            .sum()
+                    })
+                    .collect();
+                if values.iter().all(|v| v.abs() <= limit >> (degree + 1)) {
+                    break values;
+                }
+                scale /= 2;
+            };
+
+            // Leave out the values before the first and after the last, which are the answers
+            let values: Vec<String> = values[1..=length].iter().map(i64::to_string).collect();
+            out.push_str(&values.join(" "));
+            out.push('\n');
+        }
+        out
+    }
+}
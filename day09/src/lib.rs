@@ -1,51 +1,78 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use common::parse::lines;
+use common::{Answer, ParseError, Solution};
 
-fn solve_forward(data: &[i32]) -> i32 {
+mod generate;
+
+/// The differences between consecutive values, or `None` if one of them doesn't fit in an `i32`.
+fn differences(data: &[i32]) -> Option<Vec<i32>> {
+    data.windows(2).map(|x| x[1].checked_sub(x[0])).collect()
+}
+
+// Both return `None` when the numbers get too large for an `i32`: the differences of arbitrary
+// values double in size with every layer.
+fn solve_forward(data: &[i32]) -> Option<i32> {
     // Base case, all data is 0, so our prediction for the next value is 0
     // (aka this is the lowest layer)
     if data.iter().all(|&x| x == 0) {
-        return 0;
+        return Some(0);
     }
 
     // inductive case, we have to build the next array and recurse
-    let next_data = data.windows(2).map(|x| x[1] - x[0]).collect::<Vec<i32>>();
+    let next_data = differences(data)?;
 
-    data[data.len() - 1] + solve_forward(&next_data)
+    data[data.len() - 1].checked_add(solve_forward(&next_data)?)
 }
 
-fn solve_backwards(data: &[i32]) -> i32 {
+fn solve_backwards(data: &[i32]) -> Option<i32> {
     // Base case, all data is 0, so our prediction for the next value is 0
     // (aka this is the lowest layer)
     if data.iter().all(|&x| x == 0) {
-        return 0;
+        return Some(0);
     }
 
     // inductive case, we have to build the next array and recurse
-    let next_data = data.windows(2).map(|x| x[1] - x[0]).collect::<Vec<i32>>();
+    let next_data = differences(data)?;
 
-    data[0] - solve_backwards(&next_data)
+    data[0].checked_sub(solve_backwards(&next_data)?)
 }
 
-pub fn part1(input: &Vec<Vec<i32>>) -> i32 {
-    input.iter().map(|x| solve_forward(&x)).sum()
+pub fn part1(input: &[Vec<i32>]) -> Option<i32> {
+    input
+        .iter()
+        .try_fold(0i32, |sum, x| sum.checked_add(solve_forward(x)?))
 }
 
-pub fn part2(input: &Vec<Vec<i32>>) -> i32 {
-    input.iter().map(|x| solve_backwards(&x)).sum()
+pub fn part2(input: &[Vec<i32>]) -> Option<i32> {
+    input
+        .iter()
+        .try_fold(0i32, |sum, x| sum.checked_add(solve_backwards(x)?))
 }
 
-pub fn read_input(filename: &str) -> Vec<Vec<i32>> {
-    let file = File::open(filename).expect("File not found");
-    let reader = BufReader::new(file);
-
-    reader
-        .lines()
-        .flatten()
-        .map(|line| {
-            line.split_whitespace()
-                .map(|num| num.parse::<i32>().unwrap())
-                .collect::<Vec<i32>>()
-        })
+pub fn parse(data: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    lines(data)
+        .map(|line| line.parse_all::<i32>(line.text, "a number"))
         .collect()
 }
+
+#[cfg(feature = "std")]
+pub fn read_input(filename: &str) -> Result<Vec<Vec<i32>>, common::InputError> {
+    Day09::read_input(filename)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
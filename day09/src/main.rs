@@ -1,10 +1,7 @@
-use day09::{part1, part2, read_input};
+use day09::Day09;
 
-fn main() {
-    let input = read_input("input.txt"); // (60.938 µs)
-    let res = part1(&input);
-    println!("Part 1: {}", res); // 1955513104 (27.388 µs)
-
-    let res = part2(&input);
-    println!("Part 2: {}", res); // 1131 (28.197 µs)
+// Usage: cargo run --release -- [--format text|json] [INPUT]
+// INPUT defaults to input.txt, and "-" reads stdin
+fn main() -> std::process::ExitCode {
+    common::cli::main::<Day09>(env!("CARGO_PKG_NAME"))
 }
@@ -0,0 +1,6 @@
+use day09::Day09;
+
+#[test]
+fn examples() {
+    common::fixtures::check::<Day09>(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+}
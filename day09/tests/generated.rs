@@ -0,0 +1,6 @@
+use day09::Day09;
+
+#[test]
+fn generated() {
+    common::generate::check::<Day09>();
+}
@@ -0,0 +1,77 @@
+//! Random pipe maps: a `size` by `size` grid with a single loop winding through part of it, and
+//! `S` somewhere on the loop. All other tiles are random pipes or ground.
+
+use common::{Generate, Rng};
+use grid::{outline, Direction, Grid, Point, Vector};
+
+use crate::Day10;
+
+const PIPES: [char; 6] = ['|', '-', 'L', 'J', '7', 'F'];
+
+/// The pipe connecting the tile in direction `a` to the one in direction `b`.
+fn pipe(a: Direction, b: Direction) -> char {
+    use Direction::*;
+
+    match (a, b) {
+        (Up, Down) | (Down, Up) => '|',
+        (Left, Right) | (Right, Left) => '-',
+        (Up, Right) | (Right, Up) => 'L',
+        (Up, Left) | (Left, Up) => 'J',
+        (Down, Left) | (Left, Down) => '7',
+        (Down, Right) | (Right, Down) => 'F',
+        _ => unreachable!("the loop never turns back on itself"),
+    }
+}
+
+impl Generate for Day10 {
+    const DEFAULT_SIZE: usize = 140;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = size.max(3);
+        // Like in the real input, the loop leaves more tiles outside than it encloses, which part 2
+        // relies on: it takes up at most 3/4 of the width and height, at a random spot. A loop
+        // around `n` nodes is `4 * n - 1` tiles wide.
+        let most = ((3 * side / 4 + 1) / 4).max(1);
+        let least = ((side / 2 + 1) / 4).clamp(1, most);
+        let (width, height) = (rng.range(least..=most), rng.range(least..=most));
+        let offset = Point::from((
+            rng.range(0..=side - (4 * width - 1)),
+            rng.range(0..=side - (4 * height - 1)),
+        ));
+
+        // The outline's corners end up on even tiles, with the tiles between them in between
+        let corners = outline(rng, width, height);
+        let mut tiles = Vec::with_capacity(2 * corners.len());
+        for (i, &(x, y)) in corners.iter().enumerate() {
+            let (nx, ny) = corners[(i + 1) % corners.len()];
+            tiles.push(offset + Vector::new(2 * x as isize, 2 * y as isize));
+            tiles.push(offset + Vector::new((x + nx) as isize, (y + ny) as isize));
+        }
+
+        let mut map = Grid::new(side, side, '.');
+        for tile in map.iter_mut() {
+            if rng.chance(0.6) {
+                *tile = *rng.pick(&PIPES);
+            }
+        }
+        let mut on_loop = Grid::new(side, side, false);
+        for (i, &tile) in tiles.iter().enumerate() {
+            let prev = tiles[(i + tiles.len() - 1) % tiles.len()];
+            let next = tiles[(i + 1) % tiles.len()];
+            let to = |other: Point| Direction::try_from(other - tile).unwrap();
+            map[tile] = pipe(to(prev), to(next));
+            on_loop[tile] = true;
+        }
+
+        // Only the loop may connect to S, or there would be more than one shape it could have
+        let start = *rng.pick(&tiles);
+        map[start] = 'S';
+        for dir in Direction::ALL {
+            let next = start.step(dir);
+            if on_loop.get_point(next) == Some(&false) {
+                map[next] = '.';
+            }
+        }
+        map.to_string()
+    }
+}
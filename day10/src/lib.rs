@@ -1,33 +1,16 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use common::{Answer, ParseError, Solution};
+use grid::{Direction, Grid, Point};
 use std::collections::HashSet;
+use tracing::{debug, enabled, trace, Level};
 
-const DEBUG: bool = false;
+mod generate;
+#[cfg(feature = "std")]
+mod render;
 
 #[derive(Debug, Clone)]
 pub struct PuzzleInput {
-    map: Vec<Vec<char>>,
-    s_x: isize,
-    s_y: isize,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-fn offset_to_dir(offset: (isize, isize)) -> Direction {
-    use Direction::*;
-    match offset {
-        (0, -1) => Up,
-        (0, 1) => Down,
-        (1, 0) => Right,
-        (-1, 0) => Left,
-        _ => unreachable!(),
-    }
+    map: Grid<char>,
+    start: Point,
 }
 
 /*
@@ -42,75 +25,38 @@ fn offset_to_dir(offset: (isize, isize)) -> Direction {
     S is the starting position of the animal; there is a pipe on this tile, but your sketch doesnt show what shape the pipe has.
 
 */
-fn does_pipe_fit(pipe_a: char, pipe_b: char, dir: (isize, isize)) -> bool {
+fn does_pipe_fit(pipe_a: char, pipe_b: char, dir: Direction) -> bool {
     use Direction::*;
-    let dir = offset_to_dir(dir); 
 
     match pipe_a {
         '|' => match dir {
-            Up => match pipe_b {
-                '|' | '7' | 'F' => true,
-                _ => false,
-            },
-            Down => match pipe_b {
-                '|' | 'J' | 'L' => true,
-                _ => false,
-            },
+            Up => matches!(pipe_b, '|' | '7' | 'F'),
+            Down => matches!(pipe_b, '|' | 'J' | 'L'),
             _ => false,
         },
         '-' => match dir {
-            Right => match pipe_b {
-                '-' | '7' | 'J' => true,
-                _ => false,
-            },
-            Left => match pipe_b {
-                '-' | 'F' | 'L' => true,
-                _ => false,
-            },
+            Right => matches!(pipe_b, '-' | '7' | 'J'),
+            Left => matches!(pipe_b, '-' | 'F' | 'L'),
             _ => false,
         },
         'L' => match dir {
-            Up => match pipe_b {
-                '|' | '7' | 'F' => true,
-                _ => false,
-            },
-            Right => match pipe_b {
-                '-' | '7' | 'J' => true,
-                _ => false,
-            },
+            Up => matches!(pipe_b, '|' | '7' | 'F'),
+            Right => matches!(pipe_b, '-' | '7' | 'J'),
             _ => false,
         },
         'J' => match dir {
-            Up => match pipe_b {
-                '|' | '7' | 'F' => true,
-                _ => false,
-            },
-            Left => match pipe_b {
-                '-' | 'F' | 'L' => true,
-                _ => false,
-            },
+            Up => matches!(pipe_b, '|' | '7' | 'F'),
+            Left => matches!(pipe_b, '-' | 'F' | 'L'),
             _ => false,
         },
         '7' => match dir {
-            Down => match pipe_b {
-                '|' | 'J' | 'L' => true,
-                _ => false,
-            },
-            Left => match pipe_b {
-                '-' | 'F' | 'L' => true,
-                _ => false,
-            },
+            Down => matches!(pipe_b, '|' | 'J' | 'L'),
+            Left => matches!(pipe_b, '-' | 'F' | 'L'),
             _ => false,
         },
         'F' => match dir {
-            Down => match pipe_b {
-                '|' | 'J' | 'L' => true,
-                _ => false,
-            },
-            Right => match pipe_b {
-                '-' | '7' | 'J' => true,
-                _ => false,
-            },
+            Down => matches!(pipe_b, '|' | 'J' | 'L'),
+            Right => matches!(pipe_b, '-' | '7' | 'J'),
             _ => false,
 
         },
@@ -120,21 +66,24 @@ fn does_pipe_fit(pipe_a: char, pipe_b: char, dir: (isize, isize)) -> bool {
 }
 
 impl PuzzleInput {
-    fn get_directions(&self, x: isize, y: isize) -> Vec<(isize, isize)> {
+    fn get_directions(&self, pos: Point) -> Vec<(Direction, Point)> {
         let mut directions = vec![];
-        let curpipe = self.map[y as usize][x as usize];
+        let curpipe = self.map[pos];
         
-        for (dx, dy) in [(0, 1), (0, -1), (1, 0), (-1, 0)] {
-            let nx = x + dx;
-            let ny = y + dy;
-
-            if nx < 0 || nx >= self.map[0].len() as isize || ny < 0 || ny >= self.map.len() as isize {
+        // Part 2's raycasting depends on which way round the loop we walk, so keep this order
+        for dir in [
+            Direction::Down,
+            Direction::Up,
+            Direction::Right,
+            Direction::Left,
+        ] {
+            let next = pos.step(dir);
+
+            let Some(&nextpipe) = self.map.get_point(next) else {
                 continue;
-            }
-
-            let nextpipe = self.map[ny as usize][nx as usize];
-            if does_pipe_fit(curpipe, nextpipe, (dx, dy)) {
-                directions.push((nx, ny));
+            };
+            if does_pipe_fit(curpipe, nextpipe, dir) {
+                directions.push((dir, next));
             }
         }
 
@@ -143,109 +92,99 @@ impl PuzzleInput {
 
     fn resolve_s(&mut self) {
         for pipe in ['|', '-', 'L', 'J', '7', 'F'] {
-            self.map[self.s_y as usize][self.s_x as usize] = pipe;
-            if self.get_directions(self.s_x, self.s_y).len() == 2 {
-                if DEBUG {
-                    println!("S is a {} pipe", pipe);
-                }
+            self.map[self.start] = pipe;
+            if self.get_directions(self.start).len() == 2 {
+                debug!(%pipe, "resolved S");
                 break
             }
         }
     }
 
-    fn raycast(&self, x: isize, y: isize, dir: (isize, isize), set: &mut HashSet<(isize, isize)>, set_inv: &mut HashSet<(isize, isize)>) {
-        self._raycast(x, y, dir, set);
-        self._raycast(x, y, (-dir.0, -dir.1), set_inv);
+    fn raycast(
+        &self,
+        pos: Point,
+        dir: Direction,
+        set: &mut HashSet<Point>,
+        set_inv: &mut HashSet<Point>,
+    ) {
+        self._raycast(pos, dir, set);
+        self._raycast(pos, dir.reverse(), set_inv);
     }
 
-    fn _raycast(&self, x: isize, y: isize, dir: (isize, isize), set: &mut HashSet<(isize, isize)>) {
-        let pipe = self.map[y as usize][x as usize];
-        let _dir = offset_to_dir(dir);
+    fn _raycast(&self, pos: Point, dir: Direction, set: &mut HashSet<Point>) {
+        use Direction::*;
+        let pipe = self.map[pos];
         match pipe {
             'F' => {
-                if _dir == Direction::Up {
-                    self.__raycast(x, y, (-1, 0), set); // Up
-                    self.__raycast(x, y, (0, -1), set); // Left
+                if dir == Up {
+                    self.__raycast(pos, Left, set);
+                    self.__raycast(pos, Up, set);
                 }
             },
             '7' => {
-                if _dir == Direction::Right {
-                    self.__raycast(x, y, (1, 0), set);  // Right
-                    self.__raycast(x, y, (0, -1), set); // Up
+                if dir == Right {
+                    self.__raycast(pos, Right, set);
+                    self.__raycast(pos, Up, set);
                 }
             },
             'J' => {
-                if _dir == Direction::Down {
-                    self.__raycast(x, y, (0, 1), set);   // Down
-                    self.__raycast(x, y, (1, 0), set);   // Right
+                if dir == Down {
+                    self.__raycast(pos, Down, set);
+                    self.__raycast(pos, Right, set);
                 }
             },
             'L' => {
-                if _dir == Direction::Left {
-                    self.__raycast(x, y, (-1, 0), set);  // Left
-                    self.__raycast(x, y, (0, 1), set);   // Down
+                if dir == Left {
+                    self.__raycast(pos, Left, set);
+                    self.__raycast(pos, Down, set);
                 }
             },
             '|' | '-' => {
-                self.__raycast(x, y, dir, set);
+                self.__raycast(pos, dir, set);
             }
             _ => unreachable!(),
         }
     }
 
-    fn __raycast(&self, x: isize, y: isize, dir: (isize, isize), set: &mut HashSet<(isize, isize)>) {
-        let mut x = x;
-        let mut y = y;
+    fn __raycast(&self, pos: Point, dir: Direction, set: &mut HashSet<Point>) {
+        let mut pos = pos;
         loop {
-            x += dir.0;
-            y += dir.1;
-
-            if x < 0 || x >= self.map[0].len() as isize || y < 0 || y >= self.map.len() as isize {
-                break;
-            }
+            pos = pos.step(dir);
 
-            if self.map[y as usize][x as usize] != '.' {
+            if self.map.get_point(pos) != Some(&'.') {
                 break;
             }
 
-            set.insert((x, y));
-        }
-    }
-
-    fn print_map(&self) {
-        for row in &self.map {
-            for c in row {
-                print!("{}", c);
-            }
-            println!();
+            set.insert(pos);
         }
     }
 }
 
-pub fn part1(input: &PuzzleInput) -> isize {
+pub fn part1(input: &PuzzleInput) -> Option<isize> {
     let mut input = input.clone();
 
     // Step 1: figure out what S should be.
     input.resolve_s();
 
     // Step 2: follow the pipes until we loop back to S.
-    let mut x = input.s_x;
-    let mut y = input.s_y;
+    let mut pos = input.start;
     let mut visited = HashSet::new();
-    visited.insert((x, y));
+    visited.insert(pos);
 
     let mut steps = 0;
     loop {
-        let directions = input.get_directions(x, y);
-        assert!(directions.len() == 2);
+        let directions = input.get_directions(pos);
+        if directions.len() != 2 {
+            // S isn't on a loop after all, the pipes run into a dead end (or a junction)
+            return None;
+        }
         let mut flag = false;
-        for (nx, ny) in directions {
-            if visited.contains(&(nx, ny)) {
+        for (_dir, next) in directions {
+            if visited.contains(&next) {
                 continue;
             }
-            visited.insert((nx, ny));
-            x = nx;
-            y = ny;
+            visited.insert(next);
+            pos = next;
             flag = true;
             break;
         }
@@ -254,10 +193,13 @@ pub fn part1(input: &PuzzleInput) -> isize {
             break;
         }
     }
-    (steps + 1) / 2
+    Some((steps + 1) / 2)
 }
 
-pub fn part2(input: &PuzzleInput) -> usize {
+/// Follows the loop through S, and finds the tiles on either side of it. Returns the map with only
+/// the loop left on it (S replaced by its pipe), and the tiles on both sides. `None` if S isn't on
+/// a loop.
+fn sides(input: &PuzzleInput) -> Option<(PuzzleInput, HashSet<Point>, HashSet<Point>)> {
     let mut input = input.clone();
 
     // Step 1: figure out what S should be.
@@ -265,22 +207,22 @@ pub fn part2(input: &PuzzleInput) -> usize {
 
     // Step 2: follow the pipes until we loop back to S.
     //         We'll use this to make a clean copy of the map, without garbage.
-    let mut x = input.s_x;
-    let mut y = input.s_y;
+    let mut pos = input.start;
     let mut visited = HashSet::new();
-    visited.insert((x, y));
+    visited.insert(pos);
 
     loop {
-        let directions = input.get_directions(x, y);
-        assert!(directions.len() == 2);
+        let directions = input.get_directions(pos);
+        if directions.len() != 2 {
+            return None;
+        }
         let mut flag = false;
-        for (nx, ny) in directions {
-            if visited.contains(&(nx, ny)) {
+        for (_dir, next) in directions {
+            if visited.contains(&next) {
                 continue;
             }
-            visited.insert((nx, ny));
-            x = nx;
-            y = ny;
+            visited.insert(next);
+            pos = next;
             flag = true;
             break;
         }
@@ -290,57 +232,38 @@ pub fn part2(input: &PuzzleInput) -> usize {
     }
     
     // Replace everything that's not in visited with '.'
-    for y in 0..input.map.len() {
-        for x in 0..input.map[0].len() {
-            if !visited.contains(&(x as isize, y as isize)) {
-                input.map[y][x] = '.';
+    for y in 0..input.map.height() {
+        for x in 0..input.map.width() {
+            if !visited.contains(&Point::from((x, y))) {
+                input.map[(x, y)] = '.';
             }
         }
     }
 
-    if DEBUG {
-        input.print_map();
-    }
+    trace!("pipe loop:\n{}", input.map);
 
 
     // Step 3: Start a second walk from S, but this time raycast to find all areas that are on one side of a pipe.
-    let mut x = input.s_x;
-    let mut y = input.s_y;
+    let mut pos = input.start;
     let mut visited = HashSet::new();
     let mut spotted = HashSet::new();
     let mut spotted2 = HashSet::new();
-    visited.insert((x, y));
+    visited.insert(pos);
 
     loop {
-        let directions = input.get_directions(x, y);
-        assert!(directions.len() == 2);
+        let directions = input.get_directions(pos);
+        if directions.len() != 2 {
+            return None;
+        }
         let mut flag = false;
-        for (nx, ny) in directions {
-            if visited.contains(&(nx, ny)) {
+        for (dir, next) in directions {
+            if visited.contains(&next) {
                 continue;
             }
-            visited.insert((nx, ny));
-            match (nx - x, ny - y) {
-                (1, 0) => {
-                    // moved right, so raycast up
-                    input.raycast(nx, ny, (0, -1), &mut spotted, &mut spotted2);
-                },
-                (-1, 0) => {
-                    // moved left, so raycast down
-                    input.raycast(nx, ny, (0, 1), &mut spotted, &mut spotted2);
-                },
-                (0, 1) => {
-                    // moved down, so raycast right
-                    input.raycast(nx, ny, (1, 0), &mut spotted, &mut spotted2);
-                },
-                (0, -1) => {
-                    // moved up, so raycast left
-                    input.raycast(nx, ny, (-1, 0), &mut spotted, &mut spotted2);
-                },
-                _ => unreachable!(),
-            };
-            x = nx;
-            y = ny;
+            visited.insert(next);
+            // Raycast to the left of the direction we moved in, e.g. up after moving right
+            input.raycast(next, dir.turn_left(), &mut spotted, &mut spotted2);
+            pos = next;
             flag = true;
             break;
         }
@@ -349,55 +272,77 @@ pub fn part2(input: &PuzzleInput) -> usize {
         }
     }
 
-    // overwrite everything in spotted with 'S'
-    for (x, y) in &spotted {
-        input.map[*y as usize][*x as usize] = 'o';
-    }
-    for (x, y) in &spotted2 {
-        input.map[*y as usize][*x as usize] = 'I';
+    debug!(
+        pipe = visited.len(),
+        left = spotted.len(),
+        right = spotted2.len(),
+        "areas on either side of the loop"
+    );
+    if enabled!(Level::TRACE) {
+        // Mark the tiles on either side, on a copy so the map stays clean
+        let mut marked = input.map.clone();
+        for &pos in &spotted {
+            marked[pos] = 'o';
+        }
+        for &pos in &spotted2 {
+            marked[pos] = 'I';
+        }
+        trace!("sides of the loop:\n{}", marked);
     }
 
-    if DEBUG {
-        println!();
-        input.print_map();
-    }
+    Some((input, spotted, spotted2))
+}
 
-    let pipe_area = visited.len();
-    let spotted_area = spotted.len();
-    let spotted2_area = spotted2.len();
+pub fn part2(input: &PuzzleInput) -> Option<usize> {
+    let (_, spotted, spotted2) = sides(input)?;
+    Some(usize::min(spotted.len(), spotted2.len()))
+}
 
-    if DEBUG {
-        println!("Pipe area: {}", pipe_area);
-        println!("Spotted area: {}", spotted_area);
-        println!("Spotted2 area: {}", spotted2_area);
+pub fn parse(data: &str) -> Result<PuzzleInput, ParseError> {
+    let map = Grid::parse(data, "one of '|-LJ7F.S'", |c| "|-LJ7F.S".contains(c).then_some(c))?;
+
+    let mut starts = map
+        .positions()
+        .filter(|&pos| map[pos] == 'S')
+        .map(Point::from);
+
+    let start = starts
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "", "expected a starting tile 'S'"))?;
+    if let Some(Point { x, y }) = starts.next() {
+        return Err(ParseError::new(
+            y as usize + 1,
+            x as usize + 1,
+            "S",
+            "expected only one starting tile",
+        ));
     }
 
-    usize::min(spotted_area, spotted2_area)
+    Ok(PuzzleInput {
+        map,
+        start,
+    })
+}
+
+#[cfg(feature = "std")]
+pub fn read_input(filename: &str) -> Result<PuzzleInput, common::InputError> {
+    Day10::read_input(filename)
 }
 
-pub fn read_input(filename: &str) -> PuzzleInput {
-    let file = File::open(filename).expect("File not found");
-    let reader = BufReader::new(file);
-
-    let map: Vec<_> = reader
-            .lines()
-            .flatten()
-            .map(|line| line.chars().collect::<Vec<char>>())
-            .collect();
-
-    let (s_x, s_y) = map
-        .iter()
-        .enumerate()
-        .find_map(|(y, row)| {
-            row.iter()
-                .enumerate()
-                .find_map(|(x, c)| if *c == 'S' { Some((x as isize, y as isize)) } else { None })
-        })
-        .unwrap();
-
-    PuzzleInput {
-        map,
-        s_x,
-        s_y,
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
     }
 }
@@ -1,10 +1,7 @@
-use day10::{part1, part2, read_input};
+use day10::Day10;
 
-fn main() {
-    let input = read_input("input.txt"); // (30.745 µs)
-    let res = part1(&input);
-    println!("Part 1: {}", res); // 6942 (963.84 µs)
-
-    let res = part2(&input);
-    println!("Part 2: {}", res); // 297 (2.4916 ms)
+// Usage: cargo run --release -- [--format text|json] [INPUT]
+// INPUT defaults to input.txt, and "-" reads stdin
+fn main() -> std::process::ExitCode {
+    common::cli::main::<Day10>(env!("CARGO_PKG_NAME"))
 }
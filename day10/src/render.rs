@@ -0,0 +1,78 @@
+//! The loop, with the tiles inside it and the ones outside it. Every tile is drawn 3 pixels wide
+//! (before scaling), so the pipes show their shape.
+
+use grid::{Direction, Grid, Point};
+use render::{Color, Picture, Render};
+
+use crate::{sides, Day10, PuzzleInput};
+
+const PIPE: Color = Color::rgb(0xf0, 0xf0, 0xf0);
+const START: Color = Color::rgb(0xff, 0x40, 0x40);
+const INSIDE: Color = Color::rgb(0x30, 0xa0, 0x50);
+const OUTSIDE: Color = Color::rgb(0x18, 0x20, 0x38);
+/// What's left of the map when S isn't on a loop: the pipes, on plain ground.
+const GROUND: Color = Color::rgb(0x30, 0x30, 0x30);
+
+/// The directions a pipe connects to.
+fn connections(pipe: char) -> &'static [Direction] {
+    use Direction::*;
+    match pipe {
+        '|' => &[Up, Down],
+        '-' => &[Left, Right],
+        'L' => &[Up, Right],
+        'J' => &[Up, Left],
+        '7' => &[Down, Left],
+        'F' => &[Down, Right],
+        _ => &[],
+    }
+}
+
+/// Draws every tile as 3x3 pixels: the background, and the pipe from the center to the sides it
+/// connects to.
+fn draw(
+    map: &Grid<char>,
+    background: impl Fn(Point) -> Color,
+    pipe: impl Fn(Point) -> Color,
+) -> Grid<Color> {
+    let mut picture = Grid::new(map.width() * 3, map.height() * 3, Color::BLACK);
+    for ((x, y), &tile) in map.enumerate() {
+        let pos = Point::from((x, y));
+        let center = Point::from((x * 3 + 1, y * 3 + 1));
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                picture[Point::new(center.x + dx, center.y + dy)] = background(pos);
+            }
+        }
+        if connections(tile).is_empty() {
+            continue;
+        }
+        picture[center] = pipe(pos);
+        for &dir in connections(tile) {
+            picture[center.step(dir)] = pipe(pos);
+        }
+    }
+    picture
+}
+
+impl Render for Day10 {
+    fn render(input: &PuzzleInput) -> Picture {
+        let pipe = |pos: Point| match pos == input.start {
+            true => START,
+            false => PIPE,
+        };
+        let Some((clean, spotted, spotted2)) = sides(input) else {
+            return Picture::Still(draw(&input.map, |_| GROUND, pipe));
+        };
+
+        // The same choice of inside as part 2
+        let inside = match spotted.len() <= spotted2.len() {
+            true => spotted,
+            false => spotted2,
+        };
+        let background = |pos: Point| match inside.contains(&pos) {
+            true => INSIDE,
+            false => OUTSIDE,
+        };
+        Picture::Still(draw(&clean.map, background, pipe))
+    }
+}
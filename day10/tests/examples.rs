@@ -0,0 +1,6 @@
+use day10::Day10;
+
+#[test]
+fn examples() {
+    common::fixtures::check::<Day10>(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+}
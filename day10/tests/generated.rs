@@ -0,0 +1,6 @@
+use day10::Day10;
+
+#[test]
+fn generated() {
+    common::generate::check::<Day10>();
+}
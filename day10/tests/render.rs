@@ -0,0 +1,17 @@
+use common::Solution;
+use day10::Day10;
+use render::{Picture, Render};
+
+#[test]
+fn tiles_are_three_pixels_wide() {
+    let text = include_str!("../fixtures/larger.txt");
+    let input = Day10::parse(text).unwrap();
+    let Picture::Still(picture) = Day10::render(&input) else {
+        panic!("expected a still image");
+    };
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(
+        (picture.width(), picture.height()),
+        (lines[0].len() * 3, lines.len() * 3)
+    );
+}
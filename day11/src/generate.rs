@@ -0,0 +1,28 @@
+//! Random images: a `size` by `size` grid with a sprinkling of galaxies, leaving some rows and
+//! columns empty to be expanded.
+
+use common::{Generate, Rng};
+
+use crate::Day11;
+
+impl Generate for Day11 {
+    const DEFAULT_SIZE: usize = 140;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let empty_rows: Vec<bool> = (0..size).map(|_| rng.chance(0.1)).collect();
+        let empty_columns: Vec<bool> = (0..size).map(|_| rng.chance(0.1)).collect();
+
+        let mut image = String::with_capacity((size + 1) * size);
+        for &empty_row in &empty_rows {
+            for &empty_column in &empty_columns {
+                match !empty_row && !empty_column && rng.chance(0.025) {
+                    true => image.push('#'),
+                    false => image.push('.'),
+                }
+            }
+            image.push('\n');
+        }
+        image
+    }
+}
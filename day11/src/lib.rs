@@ -1,22 +1,24 @@
+use common::{Answer, ParseError, Solution, Variant};
+use grid::Grid;
 use itertools::Itertools;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+
+mod generate;
 
 #[derive(Debug, Default, Clone)]
 pub struct PuzzleInput {
-    map: HashMap<(isize, isize), bool>,
+    map: HashMap<(i64, i64), bool>,
 }
 
-pub fn part1(input: &PuzzleInput) -> isize {
+pub fn part1(input: &PuzzleInput) -> i64 {
     solve(input, 1)
 }
 
-pub fn part2(input: &PuzzleInput) -> isize {
+pub fn part2(input: &PuzzleInput) -> i64 {
     solve(input, 1_000_000 - 1)
 }
 
-pub fn solve(input: &PuzzleInput, offset: isize) -> isize {
+pub fn solve(input: &PuzzleInput, offset: i64) -> i64 {
     let x_coords: Vec<_> = input.map.keys().map(|(x, _y)| *x).sorted().collect();
     let y_coords: Vec<_> = input.map.keys().map(|(_x, y)| *y).sorted().collect();
 
@@ -43,35 +45,110 @@ pub fn solve(input: &PuzzleInput, offset: isize) -> isize {
             let (p1, p2) = (c[0], c[1]);
             let (mut x1, mut y1) = p1;
             let (mut x2, mut y2) = p2;
-            x1 += offset * x_multipliers.iter().filter(|&&x| x < x1).count() as isize;
-            x2 += offset * x_multipliers.iter().filter(|&&x| x < x2).count() as isize;
+            x1 += offset * x_multipliers.iter().filter(|&&x| x < x1).count() as i64;
+            x2 += offset * x_multipliers.iter().filter(|&&x| x < x2).count() as i64;
 
-            y1 += offset * y_multipliers.iter().filter(|&&y| y < y1).count() as isize;
-            y2 += offset * y_multipliers.iter().filter(|&&y| y < y2).count() as isize;
+            y1 += offset * y_multipliers.iter().filter(|&&y| y < y1).count() as i64;
+            y2 += offset * y_multipliers.iter().filter(|&&y| y < y2).count() as i64;
 
             (x1 - x2).abs() + (y1 - y2).abs()
         })
         .sum()
 }
 
-pub fn read_input(filename: &str) -> PuzzleInput {
-    let file = File::open(filename).expect("File not found");
-    let reader = BufReader::new(file);
-    let map = reader
-        .lines()
-        .flatten()
+/// Part 1 by the book: duplicates every empty row and column of the image, and measures the
+/// distances between the galaxies in the result.
+pub fn part1_literal(input: &PuzzleInput) -> i64 {
+    let width = input.map.keys().map(|&(x, _y)| x + 1).max().unwrap_or(0) as usize;
+    let height = input.map.keys().map(|&(_x, y)| y + 1).max().unwrap_or(0) as usize;
+    let mut image = vec![vec![false; width]; height];
+    for &(x, y) in input.map.keys() {
+        image[y as usize][x as usize] = true;
+    }
+
+    let mut rows_expanded = Vec::new();
+    for row in image {
+        if !row.contains(&true) {
+            rows_expanded.push(row.clone());
+        }
+        rows_expanded.push(row);
+    }
+    let empty_columns: Vec<bool> = (0..width)
+        .map(|x| !rows_expanded.iter().any(|row| row[x]))
+        .collect();
+    let expanded: Vec<Vec<bool>> = rows_expanded
+        .iter()
+        .map(|row| {
+            row.iter()
+                .zip(&empty_columns)
+                .flat_map(|(&galaxy, &empty)| match empty {
+                    true => vec![galaxy, galaxy],
+                    false => vec![galaxy],
+                })
+                .collect()
+        })
+        .collect();
+
+    let galaxies: Vec<(i64, i64)> = expanded
+        .iter()
         .enumerate()
-        .map(|(y, line)| {
-            line.chars()
+        .flat_map(|(y, row)| {
+            row.iter()
                 .enumerate()
-                .flat_map(|(x, c)| match c == '#' {
-                    true => Some(((x as isize, y as isize), true)),
-                    false => None,
-                })
-                .collect::<HashMap<(isize, isize), bool>>()
+                .filter(|(_x, &galaxy)| galaxy)
+                .map(move |(x, _galaxy)| (x as i64, y as i64))
         })
-        .flatten()
-        .collect::<HashMap<(isize, isize), bool>>();
+        .collect();
+    galaxies
+        .iter()
+        .tuple_combinations()
+        .map(|((x1, y1), (x2, y2))| (x1 - x2).abs() + (y1 - y2).abs())
+        .sum()
+}
+
+pub fn parse(data: &str) -> Result<PuzzleInput, ParseError> {
+    let image = Grid::parse(data, "'#' or '.'", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+
+    let map = image
+        .enumerate()
+        .filter(|(_, &galaxy)| galaxy)
+        .map(|((x, y), _)| ((x as i64, y as i64), true))
+        .collect::<HashMap<(i64, i64), bool>>();
+
+    Ok(PuzzleInput { map })
+}
+
+#[cfg(feature = "std")]
+pub fn read_input(filename: &str) -> Result<PuzzleInput, common::InputError> {
+    Day11::read_input(filename)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
 
-    PuzzleInput { map }
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![Variant {
+            name: "literal",
+            part: 1,
+            solve: |input| part1_literal(input).into(),
+        }]
+    }
 }
@@ -1,10 +1,7 @@
-use day11::{part1, part2, read_input};
+use day11::Day11;
 
-fn main() {
-    let input = read_input("input.txt"); // (41.080 µs)
-    let res = part1(&input);
-    println!("Part 1: {}", res); // 9609130 (2.5321 ms)
-
-    let res = part2(&input);
-    println!("Part 2: {}", res); // 702152204842 (2.6362 ms)
+// Usage: cargo run --release -- [--format text|json] [INPUT]
+// INPUT defaults to input.txt, and "-" reads stdin
+fn main() -> std::process::ExitCode {
+    common::cli::main::<Day11>(env!("CARGO_PKG_NAME"))
 }
@@ -0,0 +1,6 @@
+use day11::Day11;
+
+#[test]
+fn examples() {
+    common::fixtures::check::<Day11>(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+}
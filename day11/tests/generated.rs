@@ -0,0 +1,6 @@
+use day11::Day11;
+
+#[test]
+fn generated() {
+    common::generate::check::<Day11>();
+}
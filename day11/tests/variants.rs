@@ -0,0 +1,6 @@
+use day11::Day11;
+
+#[test]
+fn variants() {
+    common::variants::check::<Day11>();
+}
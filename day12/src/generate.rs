@@ -0,0 +1,43 @@
+//! Random condition records: `size` rows of up to 20 springs, some of them unknown, with the sizes
+//! of the groups of damaged springs.
+
+use common::{Generate, Rng};
+
+use crate::Day12;
+
+impl Generate for Day12 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut out = String::new();
+        for _ in 0..size.max(1) {
+            let length = rng.range(1..=20);
+            let mut springs: Vec<char> = (0..length)
+                .map(|_| match rng.chance(0.5) {
+                    true => '#',
+                    false => '.',
+                })
+                .collect();
+            // There is always at least one group of damaged springs
+            if !springs.contains(&'#') {
+                let idx = rng.below(length);
+                springs[idx] = '#';
+            }
+
+            let groups: Vec<String> = springs
+                .split(|&spring| spring == '.')
+                .filter(|group| !group.is_empty())
+                .map(|group| group.len().to_string())
+                .collect();
+            for spring in &mut springs {
+                if rng.chance(0.5) {
+                    *spring = '?';
+                }
+            }
+
+            let springs: String = springs.into_iter().collect();
+            out.push_str(&format!("{} {}\n", springs, groups.join(",")));
+        }
+        out
+    }
+}
@@ -1,29 +1,32 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use common::parse::lines;
+use common::{Answer, ParseError, Solution};
 use memoize::memoize;
 
+mod generate;
+
 type Springs = Vec<char>;
 type Nums = Vec<usize>;
 type PuzzleInput = Vec<(Springs, Nums)>;
 
+/// The number of arrangements, or `None` if there are too many to count in a `u64`.
 #[memoize]
-fn solve(springs: Vec<char>, nums: Vec<usize>) -> usize {
+fn solve(springs: Vec<char>, nums: Vec<usize>) -> Option<u64> {
     // If we've run through all of the springs, nums better be empty as well
     if springs.is_empty() {
-        return nums.is_empty() as usize;
+        return Some(nums.is_empty() as u64);
     }
 
     // If the charter says there are no more broken strings, we may not encounter
     // any more '#'s
     if nums.is_empty() {
-        return (!springs.contains(&'#')) as usize;
+        return Some((!springs.contains(&'#')) as u64);
     }
 
-    let mut res = 0;
+    let mut res = 0u64;
 
     // Case 1: the current character is a dot, or a question mark we want to fill in as a dot.
     if springs[0] == '.' || springs[0] == '?' {
-        res += solve(springs[1..].to_vec(), nums.clone())
+        res = res.checked_add(solve(springs[1..].to_vec(), nums.clone())?)?;
     }
 
     if springs[0] == '#' || springs[0] == '?' {
@@ -38,57 +41,81 @@ fn solve(springs: Vec<char>, nums: Vec<usize>) -> usize {
             && !springs[..nums[0]].contains(&'.')
             && (nums[0] == springs.len() || springs[nums[0]] != '#')
         {
-            if nums[0] == springs.len() {
-                res += solve(Vec::new(), nums[1..].to_vec())
-            } else {
-                res += solve(springs[nums[0] + 1..].to_vec(), nums[1..].to_vec())
-            }
+            let rest = match nums[0] == springs.len() {
+                true => solve(Vec::new(), nums[1..].to_vec())?,
+                false => solve(springs[nums[0] + 1..].to_vec(), nums[1..].to_vec())?,
+            };
+            res = res.checked_add(rest)?;
         }
     }
 
-    res
+    Some(res)
 }
 
-pub fn part1(input: &PuzzleInput) -> usize {
-    input
-        .iter()
-        .map(|(springs, nums)| solve(springs.clone(), nums.clone()))
-        .sum()
+pub fn part1(input: &PuzzleInput) -> Option<u64> {
+    // The cache would otherwise keep growing with every input solved in the same process
+    memoized_flush_solve();
+    input.iter().try_fold(0u64, |sum, (springs, nums)| {
+        sum.checked_add(solve(springs.clone(), nums.clone())?)
+    })
 }
 
-pub fn part2(input: &PuzzleInput) -> usize {
+pub fn part2(input: &PuzzleInput) -> Option<u64> {
+    memoized_flush_solve();
     let mut input = input.clone();
-    input
-        .iter_mut()
-        .map(|(springs, nums)| {
-            let spring_len = springs.len();
-            let nums_len = nums.len();
-            springs.push('?');
-            let springs: Vec<char> = springs
-                .iter()
-                .cycle()
-                .take(spring_len * 5 + 4)
-                .copied()
-                .collect();
-            let nums: Vec<usize> = nums.iter().cycle().take(nums_len * 5).copied().collect();
-
-            solve(springs, nums)
-        })
-        .sum()
-}
+    input.iter_mut().try_fold(0u64, |sum, (springs, nums)| {
+        let spring_len = springs.len();
+        let nums_len = nums.len();
+        springs.push('?');
+        let springs: Vec<char> = springs
+            .iter()
+            .cycle()
+            .take(spring_len * 5 + 4)
+            .copied()
+            .collect();
+        let nums: Vec<usize> = nums.iter().cycle().take(nums_len * 5).copied().collect();
 
-pub fn read_input(filename: &str) -> PuzzleInput {
-    let file = File::open(filename).expect("File not found");
-    let reader = BufReader::new(file);
+        sum.checked_add(solve(springs, nums)?)
+    })
+}
 
-    reader
-        .lines()
-        .flatten()
+pub fn parse(data: &str) -> Result<PuzzleInput, ParseError> {
+    lines(data)
         .map(|line| {
-            let (springs, nums) = line.split_once(' ').unwrap();
+            let (springs, nums) = line.split_once(line.text, " ")?;
+            if let Some((idx, c)) = springs.char_indices().find(|(_, c)| !".#?".contains(*c)) {
+                let spring = &springs[idx..idx + c.len_utf8()];
+                return Err(line.error(spring, "expected one of '.#?'"));
+            }
             let springs = springs.chars().collect();
-            let nums = nums.split(',').map(|x| x.parse().unwrap()).collect();
-            (springs, nums)
+            let nums = nums
+                .split(',')
+                .map(|x| line.parse(x, "a group size"))
+                .collect::<Result<_, _>>()?;
+            Ok((springs, nums))
         })
         .collect()
 }
+
+#[cfg(feature = "std")]
+pub fn read_input(filename: &str) -> Result<PuzzleInput, common::InputError> {
+    Day12::read_input(filename)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
@@ -1,10 +1,7 @@
-use day12::{part1, part2, read_input};
+use day12::Day12;
 
-fn main() {
-    let input = read_input("input.txt");
-    let res = part1(&input);
-    println!("Part 1: {}", res); // 7163 (86.203 µs)
-
-    let res = part2(&input);
-    println!("Part 2: {}", res); // 17788038834112 (371.34 µs)
+// Usage: cargo run --release -- [--format text|json] [INPUT]
+// INPUT defaults to input.txt, and "-" reads stdin
+fn main() -> std::process::ExitCode {
+    common::cli::main::<Day12>(env!("CARGO_PKG_NAME"))
 }
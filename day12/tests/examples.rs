@@ -0,0 +1,6 @@
+use day12::Day12;
+
+#[test]
+fn examples() {
+    common::fixtures::check::<Day12>(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+}
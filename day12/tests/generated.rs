@@ -0,0 +1,6 @@
+use day12::Day12;
+
+#[test]
+fn generated() {
+    common::generate::check::<Day12>();
+}
@@ -0,0 +1,47 @@
+//! Random patterns of ash and rocks: `size` patterns, each with a perfect reflection for part 1
+//! and a reflection with a single smudge for part 2.
+
+use common::{Generate, Rng};
+use grid::Grid;
+
+use crate::Day13;
+
+fn pattern(rng: &mut Rng) -> Grid<char> {
+    let (width, height) = (rng.range(5..=17), rng.range(5..=17));
+    // A perfect reflection between columns `mirror - 1` and `mirror`, close enough to the left
+    // edge that the columns from `2 * mirror` on are left over
+    let mirror = rng.range(1..=(width - 1) / 2);
+
+    let mut rows: Vec<Vec<char>> = (0..height)
+        .map(|_| {
+            let mut row: Vec<char> = (0..width).map(|_| *rng.pick(&['#', '.'])).collect();
+            for x in mirror..2 * mirror {
+                row[x] = row[2 * mirror - 1 - x];
+            }
+            row
+        })
+        .collect();
+    // The first two rows differ in one left over column, so they are a reflection with a smudge
+    rows[1] = rows[0].clone();
+    let smudge = rng.range(2 * mirror..width);
+    rows[1][smudge] = match rows[0][smudge] {
+        '#' => '.',
+        _ => '#',
+    };
+
+    // Either reflection can be anywhere along any side
+    let mut pattern = Grid::from_rows(rows);
+    for _ in 0..rng.below(4) {
+        pattern = pattern.rotate_clockwise();
+    }
+    pattern
+}
+
+impl Generate for Day13 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let patterns: Vec<String> = (0..size.max(1)).map(|_| pattern(rng).to_string()).collect();
+        patterns.join("\n")
+    }
+}
@@ -1,13 +1,20 @@
-type Pattern = Vec<Vec<bool>>;
+use common::parse::sections;
+use common::{Answer, ParseError, Solution};
+use grid::Grid;
+
+mod generate;
+
+type Pattern = Grid<bool>;
 type PuzzleInput = Vec<Pattern>;
 
-fn solve(input: &PuzzleInput, target_score: usize) -> usize {
+/// `None` if one of the patterns has no line of reflection with exactly `target_score` smudges.
+fn solve(input: &PuzzleInput, target_score: usize) -> Option<usize> {
     let mut ret = 0;
     'next_pattern: for pattern in input {
         // Find a vertical slice
-        for x in 1..pattern[0].len() {
+        for x in 1..pattern.width() {
             let score: usize = pattern
-                .iter()
+                .rows()
                 .map(|row| {
                     let before = row[..x].iter().rev();
                     let after = row[x..].iter();
@@ -23,15 +30,16 @@ fn solve(input: &PuzzleInput, target_score: usize) -> usize {
         }
 
         // Find a horizontal slice
-        for y in 1..pattern.len() {
+        for y in 1..pattern.height() {
             let score: usize = {
-                let before = (0..y).into_iter().rev();
-                let after = (y..pattern.len()).into_iter();
+                let before = (0..y).rev();
+                let after = y..pattern.height();
                 let iter = before.zip(after);
                 iter.map(|(a, b)| {
-                    pattern[a]
+                    pattern
+                        .row(a)
                         .iter()
-                        .zip(pattern[b].iter())
+                        .zip(pattern.row(b))
                         .map(|(&c, &d)| (c != d) as usize)
                         .sum::<usize>()
                 })
@@ -43,28 +51,51 @@ fn solve(input: &PuzzleInput, target_score: usize) -> usize {
                 continue 'next_pattern;
             }
         }
-        unreachable!("Didn't find any reflections");
+        return None;
     }
-    ret
+    Some(ret)
 }
 
-pub fn part1(input: &PuzzleInput) -> usize {
+pub fn part1(input: &PuzzleInput) -> Option<usize> {
     solve(input, 0)
 }
 
-pub fn part2(input: &PuzzleInput) -> usize {
+pub fn part2(input: &PuzzleInput) -> Option<usize> {
     solve(input, 1)
 }
 
-pub fn read_input(filename: &str) -> PuzzleInput {
-    let data = std::fs::read_to_string(filename).expect("Failed to open file");
-
-    data.split("\n\n")
+pub fn parse(data: &str) -> Result<PuzzleInput, ParseError> {
+    sections(data)
+        .into_iter()
         .map(|pattern| {
-            pattern
-                .lines()
-                .map(|line| line.chars().map(|c| c == '#').collect())
-                .collect()
+            Grid::parse_lines(pattern, "'#' or '.'", |c| match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            })
         })
         .collect()
 }
+
+#[cfg(feature = "std")]
+pub fn read_input(filename: &str) -> Result<PuzzleInput, common::InputError> {
+    Day13::read_input(filename)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
@@ -0,0 +1,6 @@
+use day13::Day13;
+
+#[test]
+fn examples() {
+    common::fixtures::check::<Day13>(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+}
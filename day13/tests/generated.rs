@@ -0,0 +1,6 @@
+use day13::Day13;
+
+#[test]
+fn generated() {
+    common::generate::check::<Day13>();
+}
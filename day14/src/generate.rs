@@ -0,0 +1,23 @@
+//! Random platforms: a `size` by `size` grid of rounded rocks, cube-shaped rocks and empty space.
+
+use common::{Generate, Rng};
+use grid::Grid;
+
+use crate::Day14;
+
+impl Generate for Day14 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let mut platform = Grid::new(size, size, '.');
+        for rock in platform.iter_mut() {
+            match rng.below(10) {
+                0 => *rock = '#',
+                1 | 2 => *rock = 'O',
+                _ => {}
+            }
+        }
+        platform.to_string()
+    }
+}
@@ -1,24 +1,21 @@
+use common::{Answer, ParseError, Solution};
+use grid::Grid;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use tracing::debug;
 
-type PuzzleInput = Vec<Vec<char>>;
+mod generate;
+#[cfg(feature = "std")]
+mod render;
+#[cfg(feature = "std")]
+mod visualise;
 
-#[allow(unused)]
-fn draw_board(board: &PuzzleInput) {
-    for row in board {
-        for char in row {
-            print!("{}", char);
-        }
-        println!();
-    }
-}
+type PuzzleInput = Grid<char>;
 
 fn score_board(board: &PuzzleInput) -> usize {
-    let h = board.len();
+    let h = board.height();
 
     board
-        .iter()
+        .rows()
         .enumerate()
         .map(|(y, row)| {
             row.iter()
@@ -29,11 +26,11 @@ fn score_board(board: &PuzzleInput) -> usize {
 }
 
 fn tilt_north(input: &mut PuzzleInput) {
-    for x in 0..input[0].len() {
+    for x in 0..input.width() {
         let mut p1 = 0;
 
-        while p1 < input.len() {
-            match input[p1][x] {
+        while p1 < input.height() {
+            match input[(x, p1)] {
                 '#' | 'O' => {}
                 // We found an empty slot, we could potentially slot in a new rock
                 '.' => {
@@ -42,8 +39,8 @@ fn tilt_north(input: &mut PuzzleInput) {
                     // - '#': move p1 up an exit the loop
                     // - 'O': we can swap this rock into the free spot at p1
                     // - End of line: we're done processing this column. --> we don't care
-                    while p2 < input.len() {
-                        match input[p2][x] {
+                    while p2 < input.height() {
+                        match input[(x, p2)] {
                             '#' => {
                                 p1 = p2;
                                 break;
@@ -53,8 +50,8 @@ fn tilt_north(input: &mut PuzzleInput) {
                             }
                             'O' => {
                                 // do the swap
-                                input[p1][x] = input[p2][x];
-                                input[p2][x] = '.';
+                                input[(x, p1)] = input[(x, p2)];
+                                input[(x, p2)] = '.';
                                 break;
                             }
                             _ => unreachable!(),
@@ -69,16 +66,16 @@ fn tilt_north(input: &mut PuzzleInput) {
 }
 
 fn tilt_south(input: &mut PuzzleInput) {
-    for x in 0..input[0].len() {
-        let mut p1: isize = (input.len() - 1) as isize;
+    for x in 0..input.width() {
+        let mut p1: isize = (input.height() - 1) as isize;
 
         while p1 >= 0 {
-            match input[p1 as usize][x] {
+            match input[(x, p1 as usize)] {
                 '#' | 'O' => {}
                 '.' => {
                     let mut p2 = p1 - 1;
                     while p2 >= 0 {
-                        match input[p2 as usize][x] {
+                        match input[(x, p2 as usize)] {
                             '#' => {
                                 p1 = p2;
                                 break;
@@ -87,8 +84,8 @@ fn tilt_south(input: &mut PuzzleInput) {
                                 p2 -= 1;
                             }
                             'O' => {
-                                input[p1 as usize][x] = input[p2 as usize][x];
-                                input[p2 as usize][x] = '.';
+                                input[(x, p1 as usize)] = input[(x, p2 as usize)];
+                                input[(x, p2 as usize)] = '.';
                                 break;
                             }
                             _ => unreachable!(),
@@ -103,16 +100,16 @@ fn tilt_south(input: &mut PuzzleInput) {
 }
 
 fn tilt_west(input: &mut PuzzleInput) {
-    for y in 0..input.len() {
+    for row in input.rows_mut() {
         let mut p1 = 0;
 
-        while p1 < input.len() {
-            match input[y][p1] {
+        while p1 < row.len() {
+            match row[p1] {
                 '#' | 'O' => {}
                 '.' => {
                     let mut p2 = p1 + 1;
-                    while p2 < input.len() {
-                        match input[y][p2] {
+                    while p2 < row.len() {
+                        match row[p2] {
                             '#' => {
                                 p1 = p2;
                                 break;
@@ -121,8 +118,8 @@ fn tilt_west(input: &mut PuzzleInput) {
                                 p2 += 1;
                             }
                             'O' => {
-                                input[y][p1] = input[y][p2];
-                                input[y][p2] = '.';
+                                row[p1] = row[p2];
+                                row[p2] = '.';
                                 break;
                             }
                             _ => unreachable!(),
@@ -137,16 +134,16 @@ fn tilt_west(input: &mut PuzzleInput) {
 }
 
 fn tilt_east(input: &mut PuzzleInput) {
-    for y in 0..input.len() {
-        let mut p1: isize = (input[0].len() - 1) as isize;
+    for y in 0..input.height() {
+        let mut p1: isize = (input.width() - 1) as isize;
 
         while p1 >= 0 {
-            match input[y][p1 as usize] {
+            match input[(p1 as usize, y)] {
                 '#' | 'O' => {}
                 '.' => {
                     let mut p2 = p1 - 1;
                     while p2 >= 0 {
-                        match input[y][p2 as usize] {
+                        match input[(p2 as usize, y)] {
                             '#' => {
                                 p1 = p2;
                                 break;
@@ -155,8 +152,8 @@ fn tilt_east(input: &mut PuzzleInput) {
                                 p2 -= 1;
                             }
                             'O' => {
-                                input[y][p1 as usize] = input[y][p2 as usize];
-                                input[y][p2 as usize] = '.';
+                                input[(p1 as usize, y)] = input[(p2 as usize, y)];
+                                input[(p2 as usize, y)] = '.';
                                 break;
                             }
                             _ => unreachable!(),
@@ -170,11 +167,20 @@ fn tilt_east(input: &mut PuzzleInput) {
     }
 }
 
+type Tilt = fn(&mut PuzzleInput);
+
+/// The tilts of a spin cycle, in order, and the way each of them tilts the platform.
+const SPIN_CYCLE: [(Tilt, &str); 4] = [
+    (tilt_north, "north"),
+    (tilt_west, "west"),
+    (tilt_south, "south"),
+    (tilt_east, "east"),
+];
+
 fn tilt_cycle(input: &mut PuzzleInput) {
-    tilt_north(input);
-    tilt_west(input);
-    tilt_south(input);
-    tilt_east(input);
+    for (tilt, _) in SPIN_CYCLE {
+        tilt(input);
+    }
 }
 
 pub fn part1(input: &PuzzleInput) -> usize {
@@ -195,7 +201,11 @@ pub fn part2(input: &PuzzleInput) -> usize {
         match board_map.get(&input) {
             Some(prev_cycle) => {
                 let cycle_len = i - prev_cycle;
-                left %= cycle_len;
+                if left > cycle_len {
+                    debug!(first = prev_cycle, again = i, cycle_len, "platform repeats");
+                }
+                // Skip ahead, but keep the current cycle in `left`
+                left = (left - 1) % cycle_len + 1;
             }
             None => {
                 board_map.insert(input.clone(), i);
@@ -207,13 +217,29 @@ pub fn part2(input: &PuzzleInput) -> usize {
     score_board(&input)
 }
 
-pub fn read_input(filename: &str) -> PuzzleInput {
-    let file = File::open(filename).expect("File not found");
-    let reader = BufReader::new(file);
+pub fn parse(data: &str) -> Result<PuzzleInput, ParseError> {
+    Grid::parse(data, "one of 'O#.'", |c| "O#.".contains(c).then_some(c))
+}
 
-    reader
-        .lines()
-        .flatten()
-        .map(|line| line.chars().collect())
-        .collect()
+#[cfg(feature = "std")]
+pub fn read_input(filename: &str) -> Result<PuzzleInput, common::InputError> {
+    Day14::read_input(filename)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
 }
@@ -1,10 +1,7 @@
-use day14::{part1, part2, read_input};
+use day14::Day14;
 
-fn main() {
-    let input = read_input("input.txt"); // (18.370 µs)
-    let res = part1(&input);
-    println!("Part 1: {}", res); // 113486 (11.696 µs)
-
-    let res = part2(&input);
-    println!("Part 2: {}", res); // 104409 (13.125 ms)
+// Usage: cargo run --release -- [--format text|json] [INPUT]
+// INPUT defaults to input.txt, and "-" reads stdin
+fn main() -> std::process::ExitCode {
+    common::cli::main::<Day14>(env!("CARGO_PKG_NAME"))
 }
@@ -0,0 +1,45 @@
+//! The rocks rolling around the platform: a frame after every tilt, until the spin cycles start
+//! repeating.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use grid::Grid;
+use render::{Animation, Color, Picture, Render};
+
+use crate::{Day14, PuzzleInput, SPIN_CYCLE};
+
+const ROUNDED: Color = Color::rgb(0xe0, 0xa0, 0x40);
+const CUBE: Color = Color::rgb(0x50, 0x50, 0x60);
+const EMPTY: Color = Color::rgb(0x10, 0x10, 0x18);
+
+/// Enough to get to the repeating part of any real input, without the GIF growing out of hand.
+const MAX_CYCLES: usize = 200;
+
+fn frame(board: &PuzzleInput) -> Grid<Color> {
+    board.map(|&tile| match tile {
+        'O' => ROUNDED,
+        '#' => CUBE,
+        _ => EMPTY,
+    })
+}
+
+impl Render for Day14 {
+    fn render(input: &PuzzleInput) -> Picture {
+        let mut animation = Animation::new(Duration::from_millis(40));
+        let mut board = input.clone();
+        animation.push(frame(&board));
+
+        let mut seen = HashSet::new();
+        for _ in 0..MAX_CYCLES {
+            for (tilt, _) in SPIN_CYCLE {
+                tilt(&mut board);
+                animation.push(frame(&board));
+            }
+            if !seen.insert(board.clone()) {
+                break;
+            }
+        }
+        Picture::Animation(animation)
+    }
+}
@@ -0,0 +1,94 @@
+//! The spin cycle of part 2, a tilt at a time, until the platform ends up the way it was after
+//! an earlier cycle.
+
+use std::collections::HashMap;
+
+use grid::Grid;
+use visualise::{Color, Simulation, Tile, Visualise};
+
+use crate::{score_board, Day14, PuzzleInput, SPIN_CYCLE};
+
+const ROUNDED: Color = Color::rgb(0xe0, 0xa0, 0x40);
+const CUBE: Color = Color::rgb(0x80, 0x80, 0x90);
+const EMPTY: Color = Color::rgb(0x40, 0x40, 0x48);
+/// Behind the rounded rocks that moved in the latest tilt.
+const MOVED: Color = Color::rgb(0x60, 0x30, 0x10);
+
+struct Spin {
+    board: PuzzleInput,
+    /// The board before the latest tilt
+    before: PuzzleInput,
+    tilts: usize,
+    /// The cycle after which the board was like this, for every cycle so far
+    seen: HashMap<PuzzleInput, usize>,
+    /// The cycle the board repeated, and the one it repeated after
+    repeat: Option<(usize, usize)>,
+}
+
+impl Simulation for Spin {
+    fn step(&mut self) -> bool {
+        if self.repeat.is_some() {
+            return false;
+        }
+        self.before = self.board.clone();
+        let (tilt, _) = SPIN_CYCLE[self.tilts % SPIN_CYCLE.len()];
+        tilt(&mut self.board);
+        self.tilts += 1;
+
+        if self.tilts.is_multiple_of(SPIN_CYCLE.len()) {
+            let cycle = self.tilts / SPIN_CYCLE.len();
+            if let Some(prev_cycle) = self.seen.insert(self.board.clone(), cycle) {
+                self.repeat = Some((prev_cycle, cycle));
+            }
+        }
+        true
+    }
+
+    fn frame(&self) -> Grid<Tile> {
+        let mut frame = self.board.map(|&tile| match tile {
+            'O' => Tile::new('O', ROUNDED),
+            '#' => Tile::new('#', CUBE),
+            _ => Tile::new('.', EMPTY),
+        });
+        for (pos, &tile) in self.board.enumerate() {
+            if tile == 'O' && self.before[pos] != 'O' {
+                frame[pos] = frame[pos].on(MOVED);
+            }
+        }
+        frame
+    }
+
+    fn status(&self) -> String {
+        let cycle = self.tilts.div_ceil(SPIN_CYCLE.len());
+        let tilted = match self.tilts {
+            0 => "not tilted yet".to_string(),
+            tilts => format!("tilted {}", SPIN_CYCLE[(tilts - 1) % SPIN_CYCLE.len()].1),
+        };
+        let mut status = format!(
+            "cycle {}, {}, load {}",
+            cycle,
+            tilted,
+            score_board(&self.board)
+        );
+        if let Some((prev_cycle, cycle)) = self.repeat {
+            status += &format!(
+                ", repeats cycle {} every {}",
+                prev_cycle,
+                cycle - prev_cycle
+            );
+        }
+        status
+    }
+}
+
+impl Visualise for Day14 {
+    fn simulation(input: &PuzzleInput) -> Box<dyn Simulation + '_> {
+        Box::new(Spin {
+            board: input.clone(),
+            before: input.clone(),
+            tilts: 0,
+            seen: HashMap::new(),
+            repeat: None,
+        })
+    }
+}
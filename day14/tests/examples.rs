@@ -0,0 +1,6 @@
+use day14::Day14;
+
+#[test]
+fn examples() {
+    common::fixtures::check::<Day14>(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+}
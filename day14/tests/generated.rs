@@ -0,0 +1,6 @@
+use day14::Day14;
+
+#[test]
+fn generated() {
+    common::generate::check::<Day14>();
+}
@@ -0,0 +1,22 @@
+use common::Solution;
+use day14::Day14;
+use render::{Picture, Render};
+
+#[test]
+fn a_frame_per_tilt() {
+    let input = Day14::parse(include_str!("../fixtures/example.txt")).unwrap();
+    let Picture::Animation(animation) = Day14::render(&input) else {
+        panic!("expected an animation");
+    };
+    let frames = animation.frames();
+    // The starting position, then four tilts per cycle
+    assert_eq!(frames.len() % 4, 1);
+    assert!(frames.len() > 4);
+    // Tilting doesn't move the cube-shaped rocks, or change the number of rounded ones
+    let count = |frame: &grid::Grid<render::Color>| {
+        let mut colors: Vec<_> = frame.iter().copied().collect();
+        colors.sort_by_key(|color| (color.r, color.g, color.b));
+        colors
+    };
+    assert!(frames.iter().all(|frame| count(frame) == count(&frames[0])));
+}
@@ -0,0 +1,42 @@
+use common::Solution;
+use day14::Day14;
+use visualise::{Viewer, Visualise};
+
+#[test]
+fn after_one_cycle() {
+    let input = Day14::parse(include_str!("../fixtures/example.txt")).unwrap();
+    let mut viewer = Viewer::new("Day 14", Day14::simulation(&input));
+    viewer.advance(4);
+    let expected = [
+        "┌ Day 14 ────────────────────────────────┐",
+        "│.....#....                              │",
+        "│....#...O#                              │",
+        "│...OO##...                              │",
+        "│.OO#......                              │",
+        "│.....OOO#.                              │",
+        "│.O#...O#.#                              │",
+        "│....O#....                              │",
+        "│......OOOO                              │",
+        "│#...O###..                              │",
+        "│#..OO#....                              │",
+        "└────────────────────────────────────────┘",
+        "step 4 | 16 steps/s | cycle 1, tilted east",
+        "space pause  s step  +/- speed  arrows/hjk",
+    ];
+    assert_eq!(viewer.snapshot(42, 14), expected.join("\n"));
+}
+
+#[test]
+fn stops_once_it_repeats() {
+    let input = Day14::parse(include_str!("../fixtures/example.txt")).unwrap();
+    let mut simulation = Day14::simulation(&input);
+    let mut tilts = 0;
+    while simulation.step() {
+        tilts += 1;
+    }
+    assert_eq!(tilts, 40);
+    assert_eq!(
+        simulation.status(),
+        "cycle 10, tilted east, load 69, repeats cycle 3 every 7"
+    );
+}
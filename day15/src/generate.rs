@@ -0,0 +1,32 @@
+//! Random initialization sequences: `size` steps, inserting and removing lenses with a pool of
+//! labels.
+
+use common::{Generate, Rng};
+
+use crate::Day15;
+
+impl Generate for Day15 {
+    const DEFAULT_SIZE: usize = 4000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let labels: Vec<String> = (0..size / 8 + 1)
+            .map(|_| {
+                (0..rng.range(2..=6))
+                    .map(|_| (b'a' + rng.below(26) as u8) as char)
+                    .collect()
+            })
+            .collect();
+
+        let steps: Vec<String> = (0..size)
+            .map(|_| {
+                let label = rng.pick(&labels);
+                match rng.chance(0.3) {
+                    true => format!("{}-", label),
+                    false => format!("{}={}", label, rng.range(1..=9)),
+                }
+            })
+            .collect();
+        steps.join(",") + "\n"
+    }
+}
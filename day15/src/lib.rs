@@ -1,5 +1,7 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use common::parse::lines;
+use common::{Answer, ParseError, Solution};
+
+mod generate;
 
 type PuzzleInput = String;
 
@@ -10,7 +12,7 @@ struct Hasher {
 
 impl Hasher {
     fn update(&mut self, byte: u8) {
-        self.cur = (self.cur+byte) * 17
+        self.cur = self.cur.wrapping_add(byte).wrapping_mul(17)
     }
     fn reset(&mut self) {
         self.cur = 0;
@@ -65,13 +67,13 @@ impl HashMapButFestive {
     }
 
     fn remove(&mut self, key: &str) {
-        let hash = self.hasher.hash_string(&key);
+        let hash = self.hasher.hash_string(key);
 
         // find the correct bucket
         let bucket = self.buckets.get_mut(hash as usize).unwrap();
 
         // update?
-        match bucket.iter().position(|elem| &elem.key == key) {
+        match bucket.iter().position(|elem| elem.key == key) {
             Some(index) => {
                 bucket.remove(index);
             },
@@ -123,11 +125,57 @@ pub fn part2(input: &PuzzleInput) -> usize {
     hashmap.power()
 }
 
-pub fn read_input(filename: &str) -> PuzzleInput {
-    let file = File::open(filename).expect("File not found");
-    let reader = BufReader::new(file);
-    
-    reader.lines()
-        .flatten()
-        .collect()
+pub fn parse(data: &str) -> Result<PuzzleInput, ParseError> {
+    let mut input = String::new();
+    for line in lines(data) {
+        for step in line.text.split(',') {
+            let label_end = step
+                .find(['=', '-'])
+                .ok_or_else(|| line.error(step, "expected a step like 'label=1' or 'label-'"))?;
+            let (label, operation) = step.split_at(label_end);
+            if label.is_empty() || !label.bytes().all(|b| b.is_ascii_lowercase()) {
+                return Err(line.error(label, "expected a label of lowercase letters"));
+            }
+            match operation.strip_prefix('=') {
+                Some(focal_length) => {
+                    // Keeps the focusing power from overflowing
+                    line.parse::<u8>(focal_length, "a focal length below 256")?;
+                }
+                None if operation == "-" => {}
+                None => return Err(line.error(&operation[1..], "expected ','")),
+            }
+        }
+        // Every line holds whole steps, so a line break separates two of them
+        if !input.is_empty() {
+            input.push(',');
+        }
+        input.push_str(line.text);
+    }
+    match input.is_empty() {
+        true => Err(ParseError::new(1, 1, "", "expected a list of steps")),
+        false => Ok(input),
+    }
+}
+
+#[cfg(feature = "std")]
+pub fn read_input(filename: &str) -> Result<PuzzleInput, common::InputError> {
+    Day15::read_input(filename)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
 }
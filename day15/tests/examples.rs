@@ -0,0 +1,6 @@
+use day15::Day15;
+
+#[test]
+fn examples() {
+    common::fixtures::check::<Day15>(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+}
@@ -0,0 +1,6 @@
+use day15::Day15;
+
+#[test]
+fn generated() {
+    common::generate::check::<Day15>();
+}
@@ -0,0 +1,109 @@
+//! Beams traced a tile at a time, for rendering and visualising part 1. Solving uses the tight loop
+//! in `simulate` instead, which doesn't stop between tiles.
+
+use grid::{Direction, Grid, Point};
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use crate::PuzzleInput;
+
+/// The beams entering the contraption at one spot, traced a tile at a time.
+pub(crate) struct Beams<'a> {
+    pub(crate) input: &'a PuzzleInput,
+    // Keep a separate map to color in
+    pub(crate) energized: Grid<bool>,
+    /// The beams a splitter sent off, still to be traced
+    pub(crate) work: VecDeque<(Point, Direction)>,
+    seen: HashSet<(Point, Direction)>,
+    // A beam can also go round in circles between mirrors alone, without ever hitting a splitter
+    turns: HashSet<(Point, Direction)>,
+    /// The beam being traced, at the tile it's about to enter
+    pub(crate) beam: Option<(Point, Direction)>,
+}
+
+impl<'a> Beams<'a> {
+    pub(crate) fn new(input: &'a PuzzleInput, start: Point, dir: Direction) -> Self {
+        Beams {
+            input,
+            energized: Grid::new(input.width(), input.height(), false),
+            work: VecDeque::from([(start, dir)]),
+            seen: HashSet::new(),
+            turns: HashSet::new(),
+            beam: None,
+        }
+    }
+
+    /// Moves the current beam a tile ahead, or takes the next one off the work queue once it has
+    /// left the contraption. Returns `false` once all beams are done.
+    pub(crate) fn step(&mut self) -> bool {
+        let Some((pos, dir)) = self.beam.or_else(|| self.work.pop_front()) else {
+            return false;
+        };
+        self.beam = self.trace(pos, dir);
+        true
+    }
+
+    /// Energizes the tile at `pos`, and returns where the beam goes next, if anywhere.
+    fn trace(&mut self, pos: Point, mut dir: Direction) -> Option<(Point, Direction)> {
+        let &tile = self.input.get_point(pos)?;
+        // color unconditionally, it's Christmas after all
+        self.energized[pos] = true;
+        match tile {
+            '.' => {}
+            '/' => {
+                dir = match dir.is_horizontal() {
+                    true => dir.turn_left(),
+                    false => dir.turn_right(),
+                };
+                if !self.turns.insert((pos, dir)) {
+                    return None;
+                }
+            }
+            '\\' => {
+                dir = match dir.is_horizontal() {
+                    true => dir.turn_right(),
+                    false => dir.turn_left(),
+                };
+                if !self.turns.insert((pos, dir)) {
+                    return None;
+                }
+            }
+            '|' => match dir.is_horizontal() {
+                true => {
+                    // split into two. This beam spawns two copies and ends here
+                    let job_up = (pos.step(Direction::Up), Direction::Up);
+                    let job_down = (pos.step(Direction::Down), Direction::Down);
+                    self.split([job_down, job_up]);
+                    return None;
+                }
+                false => { /* no-op */ }
+            },
+            '-' => match dir.is_horizontal() {
+                false => {
+                    let job_left = (pos.step(Direction::Left), Direction::Left);
+                    let job_right = (pos.step(Direction::Right), Direction::Right);
+                    self.split([job_left, job_right]);
+                    return None;
+                }
+                true => { /* no-op */ }
+            },
+
+            _ => unreachable!(),
+        }
+        Some((pos.step(dir), dir))
+    }
+
+    fn split(&mut self, jobs: [(Point, Direction); 2]) {
+        for job in jobs {
+            if self.seen.insert(job) {
+                self.work.push_front(job);
+            }
+        }
+    }
+
+    /// The number of tiles energized so far.
+    pub(crate) fn count(&self) -> usize {
+        // Count all the colored cells in our scratch map
+        self.energized.iter().filter(|&&x| x).count()
+    }
+}
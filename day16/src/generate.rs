@@ -0,0 +1,78 @@
+//! Random contraptions: a `size` by `size` grid of mostly empty space, with mirrors and splitters.
+
+use common::{Generate, Rng};
+use grid::{Direction, Grid, Point};
+
+use crate::Day16;
+
+const DEVICES: [char; 4] = ['/', '\\', '|', '-'];
+
+/// Where a beam entering `pos` heading `dir` goes next, unless it leaves the grid or is split.
+fn next(contraption: &Grid<char>, pos: Point, dir: Direction) -> Option<(Point, Direction)> {
+    let dir = match contraption[pos] {
+        '/' if dir.is_horizontal() => dir.turn_left(),
+        '/' => dir.turn_right(),
+        '\\' if dir.is_horizontal() => dir.turn_right(),
+        '\\' => dir.turn_left(),
+        '|' if dir.is_horizontal() => return None,
+        '-' if !dir.is_horizontal() => return None,
+        _ => dir,
+    };
+    let pos = pos.step(dir);
+    contraption.get_point(pos).map(|_| (pos, dir))
+}
+
+/// The tiles of a loop that a beam could go round forever, if there is one.
+fn find_loop(contraption: &Grid<char>) -> Option<Vec<Point>> {
+    let index = |(pos, dir): (Point, Direction)| {
+        let dir = Direction::ALL.iter().position(|&d| d == dir).unwrap();
+        (pos.y as usize * contraption.width() + pos.x as usize) * 4 + dir
+    };
+    // 0: not visited yet, 1: on the beam being followed, 2: known to end
+    let mut state = vec![0u8; contraption.cells().len() * 4];
+    for pos in contraption.positions().map(Point::from) {
+        for dir in Direction::ALL {
+            let mut beam = Vec::new();
+            let mut at = Some((pos, dir));
+            while let Some(step) = at.filter(|&step| state[index(step)] == 0) {
+                state[index(step)] = 1;
+                beam.push(step);
+                at = next(contraption, step.0, step.1);
+            }
+            if let Some(step) = at.filter(|&step| state[index(step)] == 1) {
+                let start = beam.iter().position(|&other| other == step).unwrap();
+                return Some(beam[start..].iter().map(|&(pos, _)| pos).collect());
+            }
+            for step in beam {
+                state[index(step)] = 2;
+            }
+        }
+    }
+    None
+}
+
+impl Generate for Day16 {
+    const DEFAULT_SIZE: usize = 110;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let mut contraption = Grid::new(size, size, '.');
+        for tile in contraption.iter_mut() {
+            if rng.chance(0.1) {
+                *tile = *rng.pick(&DEVICES);
+            }
+        }
+
+        // The solution follows every beam until it leaves or splits, so it would never finish if
+        // a beam could go round in circles. The real input has no such loops, so take out one of
+        // the mirrors of every loop there is.
+        while let Some(tiles) = find_loop(&contraption) {
+            let mirrors: Vec<Point> = tiles
+                .into_iter()
+                .filter(|&pos| matches!(contraption[pos], '/' | '\\'))
+                .collect();
+            contraption[*rng.pick(&mirrors)] = '.';
+        }
+        contraption.to_string()
+    }
+}
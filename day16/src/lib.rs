@@ -1,41 +1,58 @@
+use common::{Answer, ParseError, Solution};
+use grid::{Direction, Grid, Point};
 use std::collections::HashSet;
 use std::collections::VecDeque;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 
-type PuzzleInput = Vec<Vec<char>>;
+mod generate;
+#[cfg(feature = "std")]
+mod beams;
+#[cfg(feature = "std")]
+mod render;
+#[cfg(feature = "std")]
+mod visualise;
 
-type Coord = (isize, isize);
-type Dir = (isize, isize);
+type PuzzleInput = Grid<char>;
 
-fn simulate(input: &PuzzleInput, start: Coord, dir: Dir) -> usize {
+fn simulate(input: &PuzzleInput, start: Point, dir: Direction) -> usize {
     // Keep a separate map to color in
-    let mut map = input.clone();
-
-    let w = input[0].len() as isize;
-    let h = input.len() as isize;
+    let mut map = Grid::new(input.width(), input.height(), false);
 
     let mut work = VecDeque::new();
     work.push_front((start, dir));
     let mut seen = HashSet::new();
+    // A beam can also go round in circles between mirrors alone, without ever hitting a splitter
+    let mut turns = HashSet::new();
 
     while let Some(elem) = work.pop_front() {
-        let (mut x, mut y): Coord = elem.0;
-        let (mut dx, mut dy) = elem.1;
-        while x >= 0 && x < w && y >= 0 && y < h {
+        let (mut pos, mut dir) = elem;
+        while let Some(&tile) = input.get_point(pos) {
             // color unconditionally, it's Christmas after all
-            map[y as usize][x as usize] = '#';
-            match input[y as usize][x as usize] {
+            map[pos] = true;
+            match tile {
                 '.' => {}
-                '/' => (dx, dy) = (-dy, -dx),
+                '/' => {
+                    dir = match dir.is_horizontal() {
+                        true => dir.turn_left(),
+                        false => dir.turn_right(),
+                    };
+                    if !turns.insert((pos, dir)) {
+                        break;
+                    }
+                }
                 '\\' => {
-                    (dx, dy) = (dy, dx);
+                    dir = match dir.is_horizontal() {
+                        true => dir.turn_right(),
+                        false => dir.turn_left(),
+                    };
+                    if !turns.insert((pos, dir)) {
+                        break;
+                    }
                 }
-                '|' => match (dx, dy) {
-                    (_, 0) => {
+                '|' => match dir.is_horizontal() {
+                    true => {
                         // split into two. This job spawns two copies and ends here
-                        let job_up = ((x, y - 1), (0, -1));
-                        let job_down = ((x, y + 1), (0, 1));
+                        let job_up = (pos.step(Direction::Up), Direction::Up);
+                        let job_down = (pos.step(Direction::Down), Direction::Down);
                         for job in [job_down, job_up] {
                             if seen.insert(job) {
                                 work.push_front(job);
@@ -43,12 +60,12 @@ fn simulate(input: &PuzzleInput, start: Coord, dir: Dir) -> usize {
                         }
                         break;
                     }
-                    _ => { /* no-op */ }
+                    false => { /* no-op */ }
                 },
-                '-' => match (dx, dy) {
-                    (0, _) => {
-                        let job_left = ((x - 1, y), (-1, 0));
-                        let job_right = ((x + 1, y), (1, 0));
+                '-' => match dir.is_horizontal() {
+                    false => {
+                        let job_left = (pos.step(Direction::Left), Direction::Left);
+                        let job_right = (pos.step(Direction::Right), Direction::Right);
                         for job in [job_left, job_right] {
                             if seen.insert(job) {
                                 work.push_front(job);
@@ -56,35 +73,34 @@ fn simulate(input: &PuzzleInput, start: Coord, dir: Dir) -> usize {
                         }
                         break;
                     }
-                    _ => { /* no-op */ }
+                    true => { /* no-op */ }
                 },
 
                 _ => unreachable!(),
             }
-            y += dy;
-            x += dx;
+            pos = pos.step(dir);
         }
     }
 
     // Count all the colored cells in our scratch map
-    map.iter().flatten().filter(|&&x| x == '#').count()
+    map.iter().filter(|&&x| x).count()
 }
 
 pub fn part1(input: &PuzzleInput) -> usize {
-    simulate(input, (0, 0), (1, 0))
+    simulate(input, Point::ORIGIN, Direction::Right)
 }
 
 pub fn part2(input: &PuzzleInput) -> usize {
     let mut max = 0;
-    for x in 0..input[0].len() {
-        for y in 0..input.len() {
-            if x != 0 && x != input[0].len() - 1 && y != 0 && y != input.len() - 1 {
+    for x in 0..input.width() {
+        for y in 0..input.height() {
+            if x != 0 && x != input.width() - 1 && y != 0 && y != input.height() - 1 {
                 // lmao O(n^2)
                 continue;
             }
 
-            let start = (x as isize, y as isize);
-            for dir in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+            let start = Point::from((x, y));
+            for dir in Direction::ALL {
                 let ret = simulate(input, start, dir);
                 if ret > max {
                     max = ret;
@@ -95,13 +111,31 @@ pub fn part2(input: &PuzzleInput) -> usize {
     max
 }
 
-pub fn read_input(filename: &str) -> PuzzleInput {
-    let file = File::open(filename).expect("File not found");
-    let reader = BufReader::new(file);
+pub fn parse(data: &str) -> Result<PuzzleInput, ParseError> {
+    Grid::parse(data, "one of './\\|-'", |c| {
+        "./\\|-".contains(c).then_some(c)
+    })
+}
+
+#[cfg(feature = "std")]
+pub fn read_input(filename: &str) -> Result<PuzzleInput, common::InputError> {
+    Day16::read_input(filename)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
 
-    reader
-        .lines()
-        .flatten()
-        .map(|line| line.chars().collect())
-        .collect()
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
 }
@@ -1,10 +1,7 @@
-use day16::{part1, part2, read_input};
+use day16::Day16;
 
-fn main() {
-    let input = read_input("input.txt"); // (20.778 µs)
-    let res = part1(&input); // 7860 (58.225 µs)
-    println!("Part 1: {}", res);
-
-    let res = part2(&input); // 8331 (43.718 ms)
-    println!("Part 2: {}", res);
+// Usage: cargo run --release -- [--format text|json] [INPUT]
+// INPUT defaults to input.txt, and "-" reads stdin
+fn main() -> std::process::ExitCode {
+    common::cli::main::<Day16>(env!("CARGO_PKG_NAME"))
 }
@@ -0,0 +1,64 @@
+//! The beam of part 1 spreading through the contraption, energizing tiles as it goes.
+
+use std::time::Duration;
+
+use grid::{Direction, Grid, Point};
+use render::{Animation, Color, Picture, Render};
+
+use crate::beams::Beams;
+use crate::{Day16, PuzzleInput};
+
+const EMPTY: Color = Color::rgb(0x10, 0x10, 0x18);
+const DEVICE: Color = Color::rgb(0x70, 0x70, 0x80);
+const ENERGIZED: Color = Color::rgb(0xff, 0xd0, 0x40);
+/// The tiles the beam reached in the latest steps.
+const FRONT: Color = Color::WHITE;
+
+/// The beams take a few steps per tile, so this many frames keep the GIF small.
+const FRAMES: usize = 150;
+
+/// The step at which every tile is first energized by a beam entering the top left going right,
+/// tracing the beams the same way part 1 does.
+fn energized_at(input: &PuzzleInput) -> Grid<Option<usize>> {
+    let mut energized = Grid::new(input.width(), input.height(), None);
+    let mut beams = Beams::new(input, Point::ORIGIN, Direction::Right);
+    let mut step = 0;
+    loop {
+        // The tile the step is about to trace, if it lies within the contraption
+        let next = beams.beam.or_else(|| beams.work.front().copied());
+        if !beams.step() {
+            break;
+        }
+        if let Some(at) = next.and_then(|(pos, _)| energized.get_point_mut(pos)) {
+            at.get_or_insert(step);
+        }
+        step += 1;
+    }
+    energized
+}
+
+impl Render for Day16 {
+    fn render(input: &PuzzleInput) -> Picture {
+        let energized = energized_at(input);
+        let last = energized.iter().flatten().copied().max().unwrap_or(0);
+        let per_frame = last / FRAMES + 1;
+
+        let mut animation = Animation::new(Duration::from_millis(40));
+        for frame in 0..=last / per_frame + 1 {
+            let now = frame * per_frame;
+            let mut picture = input.map(|&tile| match tile {
+                '.' => EMPTY,
+                _ => DEVICE,
+            });
+            for (pos, &at) in energized.enumerate() {
+                match at {
+                    Some(at) if at <= now && at + per_frame > now => picture[pos] = FRONT,
+                    Some(at) if at <= now => picture[pos] = picture[pos].mix(ENERGIZED, 0.7),
+                    _ => {}
+                }
+            }
+            animation.push(picture);
+        }
+        Picture::Animation(animation)
+    }
+}
@@ -0,0 +1,57 @@
+//! The beam of part 1 going through the contraption a tile at a time, with the beams the splitters
+//! sent off waiting in the work queue.
+
+use grid::{Direction, Grid, Point};
+use visualise::{Color, Simulation, Tile, Visualise};
+
+use crate::beams::Beams;
+use crate::{Day16, PuzzleInput};
+
+const EMPTY: Color = Color::rgb(0x40, 0x40, 0x48);
+const DEVICE: Color = Color::rgb(0xc0, 0xc0, 0xd0);
+const ENERGIZED: Color = Color::rgb(0x60, 0x48, 0x10);
+/// Behind the tile the beam being traced is about to enter.
+const BEAM: Color = Color::rgb(0xff, 0xd0, 0x40);
+/// Behind the tiles where the beams in the work queue start.
+const QUEUED: Color = Color::rgb(0x20, 0x70, 0x90);
+
+impl Simulation for Beams<'_> {
+    fn step(&mut self) -> bool {
+        Beams::step(self)
+    }
+
+    fn frame(&self) -> Grid<Tile> {
+        let mut frame = self.input.map(|&tile| match tile {
+            '.' => Tile::new('.', EMPTY),
+            tile => Tile::new(tile, DEVICE),
+        });
+        for (pos, _) in self.energized.enumerate().filter(|(_, &on)| on) {
+            frame[pos] = frame[pos].on(ENERGIZED);
+        }
+        for &(pos, _) in &self.work {
+            if frame.get_point(pos).is_some() {
+                frame[pos] = frame[pos].on(QUEUED);
+            }
+        }
+        if let Some((pos, _)) = self.beam {
+            if frame.get_point(pos).is_some() {
+                frame[pos] = frame[pos].on(BEAM);
+            }
+        }
+        frame
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "{} tiles energized, {} beams queued",
+            self.count(),
+            self.work.len()
+        )
+    }
+}
+
+impl Visualise for Day16 {
+    fn simulation(input: &PuzzleInput) -> Box<dyn Simulation + '_> {
+        Box::new(Beams::new(input, Point::ORIGIN, Direction::Right))
+    }
+}
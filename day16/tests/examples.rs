@@ -0,0 +1,6 @@
+use day16::Day16;
+
+#[test]
+fn examples() {
+    common::fixtures::check::<Day16>(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+}
@@ -0,0 +1,6 @@
+use day16::Day16;
+
+#[test]
+fn generated() {
+    common::generate::check::<Day16>();
+}
@@ -0,0 +1,26 @@
+use common::Solution;
+use day16::Day16;
+use render::{Picture, Render};
+
+#[test]
+fn beam_spreads() {
+    let input = Day16::parse(include_str!("../fixtures/example.txt")).unwrap();
+    let Picture::Animation(animation) = Day16::render(&input) else {
+        panic!("expected an animation");
+    };
+    let frames = animation.frames();
+    assert!(frames.len() > 1);
+    // Energized tiles stay energized, so every frame changes at least as many tiles as the last
+    let changed = |frame: &grid::Grid<render::Color>| {
+        let before = &frames[0];
+        frame
+            .iter()
+            .zip(before.iter())
+            .filter(|(a, b)| a != b)
+            .count()
+    };
+    assert!(frames.windows(2).all(|w| changed(&w[1]) >= changed(&w[0])));
+    // The first tile is already lit in the first frame, but in the colour of the beam's front,
+    // so it still counts as changed once the beam has moved on
+    assert_eq!(changed(frames.last().unwrap()), day16::part1(&input));
+}
@@ -0,0 +1,28 @@
+use common::Solution;
+use day16::Day16;
+use visualise::{Viewer, Visualise};
+
+#[test]
+fn splits_into_the_work_queue() {
+    let input = Day16::parse(include_str!("../fixtures/example.txt")).unwrap();
+    let mut viewer = Viewer::new("Day 16", Day16::simulation(&input));
+    // Up to the first splitter, which sends a beam up and one down
+    viewer.advance(2);
+    let snapshot = viewer.snapshot(60, 14);
+    let status = snapshot.lines().nth(12).unwrap();
+    assert_eq!(
+        status,
+        "step 2 | 16 steps/s | 2 tiles energized, 2 beams queued"
+    );
+}
+
+#[test]
+fn energizes_as_many_tiles_as_part_1() {
+    let input = Day16::parse(include_str!("../fixtures/example.txt")).unwrap();
+    let mut simulation = Day16::simulation(&input);
+    while simulation.step() {}
+    assert_eq!(simulation.status(), "46 tiles energized, 0 beams queued");
+    // Once all beams are done, the only tiles with a background are the energized ones
+    let frame = simulation.frame();
+    assert_eq!(frame.iter().filter(|tile| tile.bg.is_some()).count(), 46);
+}
@@ -0,0 +1,22 @@
+//! Random heat loss maps: a `size` by `size` grid of digits from 1 to 9, at least 5 wide so an
+//! ultra crucible can make it to the end.
+
+use common::{Generate, Rng};
+use grid::Grid;
+
+use crate::Day17;
+
+impl Generate for Day17 {
+    const DEFAULT_SIZE: usize = 141;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // On a square of at least 5, the ultra crucible can zigzag to the end in equally many
+        // moves right and down, each 4 to 10 blocks long
+        let size = size.max(5);
+        let mut map = Grid::new(size, size, 0);
+        for block in map.iter_mut() {
+            *block = rng.range(1..=9);
+        }
+        map.to_string()
+    }
+}
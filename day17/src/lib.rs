@@ -1,13 +1,20 @@
+use common::{Answer, ParseError, Solution};
+use grid::{Direction, Grid, Point};
 use std::collections::{BinaryHeap, HashMap};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 
-type PuzzleInput = Vec<Vec<u8>>;
+mod generate;
+#[cfg(feature = "std")]
+mod render;
+#[cfg(feature = "std")]
+mod visualise;
+
+type PuzzleInput = Grid<u8>;
 
 #[derive(Eq, Hash, PartialEq, PartialOrd, Ord, Default, Clone, Copy, Debug)]
 struct Elem {
-    pos: (isize, isize),
-    dir: (isize, isize),
+    pos: Point,
+    /// `None` before the first move
+    dir: Option<Direction>,
     combo: isize,
 }
 
@@ -34,124 +41,208 @@ pub enum Part {
     Part2,
 }
 
-pub fn solve(input: &PuzzleInput, part: Part) -> usize {
-    let mut visited: HashMap<Elem, isize> = HashMap::new();
-    let mut priority = BinaryHeap::new();
-
-    let (min_combo, max_combo) = match part {
-        Part::Part1 => (0, 3),
-        Part::Part2 => (4, 10),
-    };
-
-    let start = ElemDist {
-        dist: 0,
-        elem: Elem {
-            pos: (0, 0),
-            dir: (0, 0), // (make sure 'backwards' is always false initially)
-            combo: 0,    // (first move was free)
-        },
-    };
-
-    let w = input[0].len() as isize;
-    let h = input.len() as isize;
-
-    priority.push(start);
-
-    while let Some(elem_dist) = priority.pop() {
-        let dist = elem_dist.dist;
-        let elem = elem_dist.elem;
+/// Dijkstra from the top left, a state at a time.
+pub(crate) struct Search<'a> {
+    input: &'a PuzzleInput,
+    min_combo: isize,
+    max_combo: isize,
+    end: Point,
+    /// The least heat loss of every state settled
+    visited: HashMap<Elem, isize>,
+    priority: BinaryHeap<ElemDist>,
+    /// Only when tracing: the cheapest way into every state reached so far, as its heat loss and
+    /// the state before it
+    from: Option<HashMap<Elem, (isize, Elem)>>,
+    /// The state settled by the latest step
+    current: Option<Elem>,
+}
 
-        // Check that we're not moving in the same dir for too long.
-        if elem.combo > max_combo {
-            continue;
+impl<'a> Search<'a> {
+    /// A search that only finds the least heat loss.
+    pub(crate) fn new(input: &'a PuzzleInput, part: Part) -> Self {
+        let (min_combo, max_combo) = match part {
+            Part::Part1 => (0, 3),
+            Part::Part2 => (4, 10),
+        };
+
+        let start = ElemDist {
+            dist: 0,
+            elem: Elem {
+                pos: Point::ORIGIN,
+                dir: None, // (make sure 'backwards' is always false initially)
+                combo: 0,  // (first move was free)
+            },
+        };
+
+        Search {
+            input,
+            min_combo,
+            max_combo,
+            end: Point::from((input.width() - 1, input.height() - 1)),
+            visited: HashMap::new(),
+            priority: BinaryHeap::from([start]),
+            from: None,
+            current: None,
         }
+    }
 
-        if visited.contains_key(&elem) {
-            if elem.pos.0 != 0 && elem.pos.1 != 0 {
-                assert!(&dist >= visited.get(&elem).unwrap());
-            }
-            continue;
+    /// A search that also keeps track of the way to every state, for [`Search::path`].
+    pub(crate) fn tracing(input: &'a PuzzleInput, part: Part) -> Self {
+        Search {
+            from: Some(HashMap::new()),
+            ..Search::new(input, part)
         }
+    }
 
-        visited.insert(elem, dist);
+    /// Settles the closest state in the frontier, and adds its neighbours. Returns `false` once
+    /// the frontier is empty.
+    pub(crate) fn step(&mut self) -> bool {
+        let input = self.input;
+        while let Some(elem_dist) = self.priority.pop() {
+            let dist = elem_dist.dist;
+            let elem = elem_dist.elem;
 
-        for dir in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
-            // Check that we're not moving in reverse.
-            if -dir.0 == elem.dir.0 && -dir.1 == elem.dir.1 {
+            // Check that we're not moving in the same dir for too long.
+            if elem.combo > self.max_combo {
                 continue;
             }
 
-            // We're only allowed to turn if we've reached the min combo.
-            // (except for the start)
-            if elem.combo < min_combo && dir != elem.dir && elem.dir != (0, 0) {
+            if self.visited.contains_key(&elem) {
+                if elem.pos.x != 0 && elem.pos.y != 0 {
+                    assert!(dist >= self.visited[&elem]);
+                }
                 continue;
             }
 
-            // Check that the now pos will be in bounds.
-            let new_pos = (elem.pos.0 + dir.0, elem.pos.1 + dir.1);
-            if new_pos.0 < 0 || new_pos.0 >= w || new_pos.1 < 0 || new_pos.1 >= h {
-                continue;
-            }
-
-            // We can not end with a combo thats less than the min.
-            if new_pos == (w - 1, h - 1) && elem.combo < min_combo {
-                continue;
+            self.visited.insert(elem, dist);
+            self.current = Some(elem);
+
+            for dir in Direction::ALL {
+                // Check that we're not moving in reverse.
+                if elem.dir == Some(dir.reverse()) {
+                    continue;
+                }
+
+                // We're only allowed to turn if we've reached the min combo.
+                // (except for the start)
+                if elem.combo < self.min_combo && elem.dir.is_some_and(|d| d != dir) {
+                    continue;
+                }
+
+                // Check that the now pos will be in bounds.
+                let new_pos = elem.pos.step(dir);
+                let Some(&cost) = input.get_point(new_pos) else {
+                    continue;
+                };
+
+                let new_elem = Elem {
+                    pos: new_pos,
+                    dir: Some(dir),
+                    combo: match Some(dir) == elem.dir {
+                        true => elem.combo + 1,
+                        false => 1,
+                    },
+                };
+
+                // We can not end with a combo thats less than the min.
+                if new_pos == self.end && new_elem.combo < self.min_combo {
+                    continue;
+                }
+
+                if self.visited.contains_key(&new_elem) {
+                    continue;
+                }
+
+                let new_dist = dist + cost as isize;
+                if let Some(from) = &mut self.from {
+                    // The state is settled at the least heat loss it was pushed with, so the way in
+                    // that got it there is the one to keep
+                    if from.get(&new_elem).is_none_or(|&(best, _)| new_dist < best) {
+                        from.insert(new_elem, (new_dist, elem));
+                    }
+                }
+                self.priority.push(ElemDist {
+                    elem: new_elem,
+                    dist: new_dist,
+                });
             }
+            return true;
+        }
+        self.current = None;
+        false
+    }
 
-            let cost = input[new_pos.1 as usize][new_pos.0 as usize];
-
-            let new_elem = Elem {
-                pos: new_pos,
-                dir,
-                combo: match dir == elem.dir {
-                    true => elem.combo + 1,
-                    false => 1,
-                },
-            };
-
-            if visited.contains_key(&new_elem) {
-                continue;
-            }
+    /// The best state at the bottom right reached so far, if any.
+    pub(crate) fn best(&self) -> Option<Elem> {
+        self.visited
+            .iter()
+            .filter(|(elem, _)| elem.pos == self.end && elem.combo >= self.min_combo)
+            .min_by_key(|(_, &dist)| dist)
+            .map(|(&elem, _)| elem)
+    }
 
-            let new_dist = dist + cost as isize;
-            priority.push(ElemDist {
-                elem: new_elem,
-                dist: new_dist,
-            });
+    /// The blocks on the way to `elem`, starting at the top left. Panics unless the search is
+    /// [tracing](Search::tracing).
+    pub(crate) fn path(&self, elem: Elem) -> Vec<Point> {
+        let from = self.from.as_ref().expect("only a tracing search knows the way");
+        let mut path = Vec::new();
+        let mut elem = Some(elem);
+        while let Some(current) = elem {
+            path.push(current.pos);
+            elem = from.get(&current).map(|&(_, prev)| prev);
         }
+        path.reverse();
+        path
     }
+}
 
-    visited
-        .iter()
-        .filter_map(|(&elem, &val)| {
-            match elem.pos.0 == w - 1 && elem.pos.1 == h - 1 && elem.combo >= min_combo {
-                true => Some(val),
-                false => None,
-            }
-        })
-        .min()
-        .unwrap() as usize
+/// The least heat loss on the way to the bottom right, or `None` if the crucible can't get there
+/// at all (when the map is too small to ever make the turns an ultra crucible needs, say).
+pub fn solve(input: &PuzzleInput, part: Part) -> Option<usize> {
+    let mut search = Search::new(input, part);
+    while search.step() {}
+    search.best().map(|elem| search.visited[&elem] as usize)
 }
 
-pub fn part1(input: &PuzzleInput) -> usize {
+/// The blocks on a way to the bottom right with the least heat loss, starting at the top left.
+/// `None` if there is no way there.
+pub fn best_path(input: &PuzzleInput, part: Part) -> Option<Vec<Point>> {
+    let mut search = Search::tracing(input, part);
+    while search.step() {}
+    search.best().map(|elem| search.path(elem))
+}
+
+pub fn part1(input: &PuzzleInput) -> Option<usize> {
     solve(input, Part::Part1)
 }
 
-pub fn part2(input: &PuzzleInput) -> usize {
+pub fn part2(input: &PuzzleInput) -> Option<usize> {
     solve(input, Part::Part2)
 }
 
-pub fn read_input(filename: &str) -> PuzzleInput {
-    let file = File::open(filename).expect("File not found");
-    let reader = BufReader::new(file);
-
-    reader
-        .lines()
-        .flatten()
-        .map(|line| {
-            line.chars()
-                .map(|x| x.to_digit(10).unwrap() as u8)
-                .collect()
-        })
-        .collect()
+pub fn parse(data: &str) -> Result<PuzzleInput, ParseError> {
+    Grid::parse(data, "a digit", |c| c.to_digit(10).map(|x| x as u8))
+}
+
+#[cfg(feature = "std")]
+pub fn read_input(filename: &str) -> Result<PuzzleInput, common::InputError> {
+    Day17::read_input(filename)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
 }
@@ -0,0 +1,35 @@
+//! The heat loss map, from cool to hot, with the best ways of both crucibles drawn over it.
+
+use std::collections::HashSet;
+
+use render::{Color, Picture, Render};
+
+use crate::{best_path, Day17, Part, PuzzleInput};
+
+const COOL: Color = Color::rgb(0x10, 0x20, 0x40);
+const HOT: Color = Color::rgb(0xc0, 0x30, 0x10);
+const CRUCIBLE: Color = Color::rgb(0x40, 0xe0, 0x60);
+const ULTRA_CRUCIBLE: Color = Color::rgb(0x40, 0xa0, 0xff);
+/// The blocks both crucibles pass.
+const BOTH: Color = Color::WHITE;
+
+impl Render for Day17 {
+    fn render(input: &PuzzleInput) -> Picture {
+        let mut picture = input.map(|&loss| COOL.mix(HOT, (loss as f64 - 1.0) / 8.0));
+
+        let crucible: HashSet<_> = best_path(input, Part::Part1)
+            .unwrap_or_default()
+            .into_iter()
+            .collect();
+        for &pos in &crucible {
+            picture[pos] = CRUCIBLE;
+        }
+        for pos in best_path(input, Part::Part2).unwrap_or_default() {
+            picture[pos] = match crucible.contains(&pos) {
+                true => BOTH,
+                false => ULTRA_CRUCIBLE,
+            };
+        }
+        Picture::Still(picture)
+    }
+}
@@ -0,0 +1,103 @@
+//! Dijkstra's frontier spreading over the heat loss map, first for the crucible of part 1 and then
+//! for the ultra crucible of part 2, each ending with its best way to the bottom right.
+
+use std::collections::HashSet;
+
+use grid::Grid;
+use visualise::{Color, Simulation, Tile, Visualise};
+
+use crate::{Day17, Part, PuzzleInput, Search};
+
+const COOL: Color = Color::rgb(0x50, 0x70, 0xc0);
+const HOT: Color = Color::rgb(0xff, 0x50, 0x20);
+/// Behind the blocks with a settled state.
+const SETTLED: Color = Color::rgb(0x20, 0x28, 0x40);
+/// Behind the blocks with a state in the frontier.
+const FRONTIER: Color = Color::rgb(0x20, 0x70, 0x70);
+/// Behind the block of the state settled last.
+const CURRENT: Color = Color::WHITE;
+/// Behind the blocks of the best way, once found.
+const PATH: Color = Color::rgb(0x30, 0xa0, 0x40);
+
+struct Crucibles<'a> {
+    input: &'a PuzzleInput,
+    search: Search<'a>,
+    ultra: bool,
+    /// Whether the search of the current crucible has run out of states
+    done: bool,
+}
+
+impl Simulation for Crucibles<'_> {
+    fn step(&mut self) -> bool {
+        match (self.done, self.ultra) {
+            (false, _) => self.done = !self.search.step(),
+            (true, false) => {
+                self.search = Search::tracing(self.input, Part::Part2);
+                self.ultra = true;
+                self.done = false;
+            }
+            (true, true) => return false,
+        }
+        true
+    }
+
+    fn frame(&self) -> Grid<Tile> {
+        let search = &self.search;
+        let mut frame = self.input.map(|&loss| {
+            let symbol = char::from_digit(loss as u32, 10).unwrap_or('?');
+            Tile::new(symbol, COOL.mix(HOT, (loss as f64 - 1.0) / 8.0))
+        });
+        let settled: HashSet<_> = search.visited.keys().map(|elem| elem.pos).collect();
+        for pos in settled {
+            frame[pos] = frame[pos].on(SETTLED);
+        }
+        for elem_dist in &search.priority {
+            frame[elem_dist.elem.pos] = frame[elem_dist.elem.pos].on(FRONTIER);
+        }
+        if let Some(elem) = search.current {
+            frame[elem.pos] = frame[elem.pos].on(CURRENT);
+        }
+        if self.done {
+            for pos in search
+                .best()
+                .map(|elem| search.path(elem))
+                .unwrap_or_default()
+            {
+                frame[pos] = frame[pos].on(PATH);
+            }
+        }
+        frame
+    }
+
+    fn status(&self) -> String {
+        let search = &self.search;
+        let crucible = match self.ultra {
+            true => "ultra crucible",
+            false => "crucible",
+        };
+        match (self.done, search.best()) {
+            (true, Some(best)) => {
+                format!("{}: least heat loss {}", crucible, search.visited[&best])
+            }
+            (true, None) => format!("{}: can't get to the bottom right", crucible),
+            (false, _) => format!(
+                "{}: {} settled, {} in the frontier, heat loss {}",
+                crucible,
+                search.visited.len(),
+                search.priority.len(),
+                search.current.map_or(0, |elem| search.visited[&elem])
+            ),
+        }
+    }
+}
+
+impl Visualise for Day17 {
+    fn simulation(input: &PuzzleInput) -> Box<dyn Simulation + '_> {
+        Box::new(Crucibles {
+            input,
+            search: Search::tracing(input, Part::Part1),
+            ultra: false,
+            done: false,
+        })
+    }
+}
@@ -0,0 +1,6 @@
+use day17::Day17;
+
+#[test]
+fn examples() {
+    common::fixtures::check::<Day17>(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+}
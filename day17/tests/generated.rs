@@ -0,0 +1,6 @@
+use day17::Day17;
+
+#[test]
+fn generated() {
+    common::generate::check::<Day17>();
+}
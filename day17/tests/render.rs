@@ -0,0 +1,27 @@
+use common::Solution;
+use day17::{best_path, Day17, Part};
+use render::{Picture, Render};
+
+#[test]
+fn paths_over_the_map() {
+    let input = Day17::parse(include_str!("../fixtures/example1.txt")).unwrap();
+    let Picture::Still(picture) = Day17::render(&input) else {
+        panic!("expected a still image");
+    };
+    assert_eq!(
+        (picture.width(), picture.height()),
+        (input.width(), input.height())
+    );
+
+    // Both paths go from corner to corner, and cost what the parts say
+    for (part, loss) in [(Part::Part1, 102), (Part::Part2, 94)] {
+        let path = best_path(&input, part).unwrap();
+        assert_eq!(path[0], grid::Point::ORIGIN);
+        assert_eq!(
+            path.last().unwrap(),
+            &grid::Point::from((input.width() - 1, input.height() - 1))
+        );
+        let total: usize = path[1..].iter().map(|&pos| input[pos] as usize).sum();
+        assert_eq!(total, loss);
+    }
+}
@@ -0,0 +1,34 @@
+use common::Solution;
+use day17::{best_path, Day17, Part};
+use visualise::{Simulation, Visualise};
+
+/// Steps `simulation` until its status starts with `prefix`.
+fn run_until(simulation: &mut dyn Simulation, prefix: &str) {
+    while !simulation.status().starts_with(prefix) {
+        assert!(simulation.step(), "finished before {:?}", prefix);
+    }
+}
+
+#[test]
+fn both_crucibles_in_turn() {
+    let input = Day17::parse(include_str!("../fixtures/example1.txt")).unwrap();
+    let mut simulation = Day17::simulation(&input);
+    assert_eq!(
+        simulation.status(),
+        "crucible: 0 settled, 1 in the frontier, heat loss 0"
+    );
+
+    run_until(simulation.as_mut(), "crucible: least");
+    assert_eq!(simulation.status(), "crucible: least heat loss 102");
+    // The best way is drawn over the map once found
+    let path = best_path(&input, Part::Part1).unwrap();
+    let frame = simulation.frame();
+    let highlighted = path.iter().map(|&pos| frame[pos].bg).collect::<Vec<_>>();
+    assert!(highlighted
+        .windows(2)
+        .all(|w| w[0] == w[1] && w[0].is_some()));
+
+    run_until(simulation.as_mut(), "ultra crucible: least");
+    assert_eq!(simulation.status(), "ultra crucible: least heat loss 94");
+    assert!(!simulation.step());
+}
@@ -0,0 +1,103 @@
+//! Random dig plans: two unrelated loops with the same number of instructions, one with short
+//! edges for the plan as written and one with long edges hidden in the colours. `size` is roughly
+//! the number of instructions.
+
+use common::{Generate, Rng};
+use grid::{outline, Direction, Point};
+
+use crate::Day18;
+
+/// The hex plan's distances are 5 hex digits.
+const MAX_DISTANCE: usize = 0xfffff;
+
+type Instruction = (Direction, usize);
+
+/// The instructions to dig a random loop on a `nodes` by `nodes` tree, where every step of the
+/// outline becomes a distance in `distances`.
+fn dig_plan(rng: &mut Rng, nodes: usize, distances: (usize, usize)) -> Vec<Instruction> {
+    let mut corners = outline(rng, nodes, nodes);
+    // Start on a corner where the loop turns, so the first and last instruction aren't the same way
+    let direction = |from: (usize, usize), to: (usize, usize)| {
+        Direction::try_from(Point::from(to) - Point::from(from)).unwrap()
+    };
+    let turn = (0..corners.len())
+        .find(|&i| {
+            let prev = corners[(i + corners.len() - 1) % corners.len()];
+            let next = corners[(i + 1) % corners.len()];
+            direction(prev, corners[i]) != direction(corners[i], next)
+        })
+        .unwrap();
+    corners.rotate_left(turn);
+
+    // Stretch the outline by giving every row and column of the lattice its own size
+    let mut stretch = |len: usize| {
+        let mut at = vec![0];
+        for _ in 0..len {
+            at.push(at[at.len() - 1] + rng.range(distances.0..=distances.1));
+        }
+        at
+    };
+    let (xs, ys) = (stretch(2 * nodes), stretch(2 * nodes));
+
+    let mut plan: Vec<Instruction> = Vec::new();
+    for (i, &(x, y)) in corners.iter().enumerate() {
+        let (nx, ny) = corners[(i + 1) % corners.len()];
+        let dir = direction((x, y), (nx, ny));
+        let distance = xs[x].abs_diff(xs[nx]) + ys[y].abs_diff(ys[ny]);
+        match plan.last_mut() {
+            Some(last) if last.0 == dir => last.1 += distance,
+            _ => plan.push((dir, distance)),
+        }
+    }
+    plan
+}
+
+/// Splits the longest instructions in two until there are `len` of them.
+fn split(rng: &mut Rng, plan: &mut Vec<Instruction>, len: usize) {
+    while plan.len() < len {
+        let (idx, &(dir, distance)) = plan
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, (_, distance))| *distance)
+            .unwrap();
+        assert!(distance > 1, "nothing left to split");
+        let first = rng.range(1..distance);
+        plan[idx].1 = first;
+        plan.insert(idx + 1, (dir, distance - first));
+    }
+}
+
+impl Generate for Day18 {
+    const DEFAULT_SIZE: usize = 660;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let nodes = (2 * size / 3).isqrt().max(1);
+        // Every distance in the plan is at least 2, and both loops have as many steps along their
+        // outlines, so whichever has fewer instructions can always be split up further
+        let mut plan = dig_plan(rng, nodes, (2, 6));
+        let mut hex_plan = dig_plan(rng, nodes, (1, MAX_DISTANCE / (2 * nodes)));
+        split(rng, &mut plan, hex_plan.len());
+        split(rng, &mut hex_plan, plan.len());
+
+        let mut out = String::new();
+        for ((dir, distance), (hex_dir, hex_distance)) in plan.into_iter().zip(hex_plan) {
+            let dir = match dir {
+                Direction::Up => 'U',
+                Direction::Down => 'D',
+                Direction::Left => 'L',
+                Direction::Right => 'R',
+            };
+            let hex_dir = match hex_dir {
+                Direction::Right => 0,
+                Direction::Down => 1,
+                Direction::Left => 2,
+                Direction::Up => 3,
+            };
+            out.push_str(&format!(
+                "{} {} (#{:05x}{})\n",
+                dir, distance, hex_distance, hex_dir
+            ));
+        }
+        out
+    }
+}
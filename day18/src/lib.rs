@@ -1,100 +1,120 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use common::parse::{lines, Line};
+use common::{Answer, ParseError, Solution};
+use grid::Direction;
 
-type PuzzleInput = Vec<String>;
+mod generate;
 
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-fn dir_to_delta(dir: &Direction) -> (isize, isize) {
-    use Direction::*;
-    match dir {
-        Up => (0, 1),
-        Down => (0, -1),
-        Left => (-1, 0),
-        Right => (1, 0),
-    }
+#[derive(Debug, Clone)]
+pub struct PuzzleInput {
+    /// The dig plan as written
+    plan: Vec<Instruction>,
+    /// The "real" dig plan, hidden in the colour codes
+    hex_plan: Vec<Instruction>,
 }
 
+#[derive(Debug, Clone)]
 struct Instruction {
     op: Direction,
-    arg: isize,
+    arg: i64,
 }
 
-fn solve(instructions: &Vec<Instruction>) -> isize {
-    let mut points = Vec::new();
+/// The number of cubic meters of lava the lagoon holds, or `None` if it's too large to work out in
+/// an `i64`.
+fn solve(instructions: &[Instruction]) -> Option<i64> {
+    // Not `Point`s, as the hex plans go further than an `isize` reaches on 32-bit targets
+    let mut points: Vec<(i64, i64)> = Vec::new();
     let mut perim = 0;
     let (mut x, mut y) = (0, 0);
     for instruction in instructions {
-        let delta = dir_to_delta(&instruction.op);
-        x += delta.0 * instruction.arg;
-        y += delta.1 * instruction.arg;
+        let step = instruction.op.vector();
+        x += step.x as i64 * instruction.arg;
+        y += step.y as i64 * instruction.arg;
         perim += instruction.arg;
         points.push((x, y));
     }
 
     // Calculate the area using the shoelace formula
-    let mut area = 0;
+    let mut area: i64 = 0;
     for i in 0..points.len() {
         let j = (i + 1) % points.len();
-        area += points[i].0 * points[j].1;
-        area -= points[i].1 * points[j].0;
+        area = area.checked_add(points[i].0.checked_mul(points[j].1)?)?;
+        area = area.checked_sub(points[i].1.checked_mul(points[j].0)?)?;
     }
 
-    area.abs() / 2 + perim / 2 + 1
+    Some(area.checked_abs()? / 2 + perim / 2 + 1)
+}
+
+pub fn part1(input: &PuzzleInput) -> Option<i64> {
+    solve(&input.plan)
 }
 
-pub fn part1(input: &PuzzleInput) -> isize {
-    let instructions = input
-        .iter()
-        .map(|line| {
-            let mut iter = line.split(' ');
-            let op = match iter.next().unwrap() {
-                "U" => Direction::Up,
-                "D" => Direction::Down,
-                "L" => Direction::Left,
-                "R" => Direction::Right,
-                _ => panic!("Invalid direction"),
-            };
-            let arg = iter.next().unwrap().parse::<isize>().unwrap();
-
-            Instruction { op, arg }
-        })
-        .collect::<Vec<Instruction>>();
-    solve(&instructions)
+pub fn part2(input: &PuzzleInput) -> Option<i64> {
+    solve(&input.hex_plan)
 }
 
-pub fn part2(input: &PuzzleInput) -> isize {
-    let instructions = input
-        .iter()
-        .map(|line| {
-            let hex = isize::from_str_radix(
-                line.split_once('#').unwrap().1.split_once(')').unwrap().0,
-                16,
-            )
-            .unwrap();
-            let arg = (hex & 0xfffff0) >> 4;
-            let op = match hex & 0xf {
-                0 => Direction::Right,
-                1 => Direction::Down,
-                2 => Direction::Left,
-                3 => Direction::Up,
-                _ => panic!("Invalid direction"),
-            };
-
-            Instruction { op, arg }
-        })
-        .collect::<Vec<Instruction>>();
-    solve(&instructions)
+/// Parses a line like `R 6 (#70c710)` into both the plain and the hex-encoded instruction.
+fn parse_line(line: &Line) -> Result<(Instruction, Instruction), ParseError> {
+    let (op, rest) = line.split_once(line.text, " ")?;
+    let op = match op {
+        "U" => Direction::Up,
+        "D" => Direction::Down,
+        "L" => Direction::Left,
+        "R" => Direction::Right,
+        _ => return Err(line.error(op, "expected 'U', 'D', 'L' or 'R'")),
+    };
+    let (arg, color) = line.split_once(rest, " ")?;
+    let arg = line.parse::<u32>(arg, "a distance")? as i64;
+    let instruction = Instruction { op, arg };
+
+    let color = line.strip_prefix(color, "(#")?;
+    let color = color
+        .strip_suffix(')')
+        .ok_or_else(|| line.error_at_end("expected ')'"))?;
+    if color.len() != 6 || !color.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(line.error(color, "expected a colour of 6 hex digits"));
+    }
+    let hex = i64::from_str_radix(color, 16).map_err(|_| line.error(color, "expected a colour"))?;
+    let arg = (hex & 0xfffff0) >> 4;
+    let op = match hex & 0xf {
+        0 => Direction::Right,
+        1 => Direction::Down,
+        2 => Direction::Left,
+        3 => Direction::Up,
+        _ => return Err(line.error(&color[5..], "expected a direction between 0 and 3")),
+    };
+
+    Ok((instruction, Instruction { op, arg }))
 }
 
-pub fn read_input(filename: &str) -> PuzzleInput {
-    let file = File::open(filename).expect("File not found");
-    let reader = BufReader::new(file);
+pub fn parse(data: &str) -> Result<PuzzleInput, ParseError> {
+    let (plan, hex_plan) = lines(data)
+        .map(|line| parse_line(&line))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .unzip();
+
+    Ok(PuzzleInput { plan, hex_plan })
+}
 
-    reader.lines().flatten().collect()
+#[cfg(feature = "std")]
+pub fn read_input(filename: &str) -> Result<PuzzleInput, common::InputError> {
+    Day18::read_input(filename)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
 }
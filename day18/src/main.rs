@@ -1,13 +1,7 @@
-use day18::{part1, part2, read_input};
+use day18::Day18;
 
-fn main() {
-    // If there is an argument, use it as the input file name. Otherwise, use "input.txt"
-    let input_file_name = std::env::args().nth(1).unwrap_or("input.txt".to_string());
-
-    let input = read_input(&input_file_name); // (30.633 µs)
-    let res = part1(&input); // 50746 (10.705 µs)
-    println!("Part 1: {}", res);
-
-    let res = part2(&input); // 70086216556038 (11.822 µs)
-    println!("Part 2: {}", res);
+// Usage: cargo run --release -- [--format text|json] [INPUT]
+// INPUT defaults to input.txt, and "-" reads stdin
+fn main() -> std::process::ExitCode {
+    common::cli::main::<Day18>(env!("CARGO_PKG_NAME"))
 }
@@ -0,0 +1,6 @@
+use day18::Day18;
+
+#[test]
+fn examples() {
+    common::fixtures::check::<Day18>(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+}
@@ -0,0 +1,6 @@
+use day18::Day18;
+
+#[test]
+fn generated() {
+    common::generate::check::<Day18>();
+}
@@ -0,0 +1,63 @@
+//! Random workflows and parts: up to `size` workflows branching out from `in` as a tree, so every
+//! part ends up accepted or rejected, and a third as many parts to sort.
+
+use std::collections::{HashSet, VecDeque};
+
+use common::{Generate, Rng};
+
+use crate::Day19;
+
+const CATEGORIES: [char; 4] = ['x', 'm', 'a', 's'];
+
+impl Generate for Day19 {
+    const DEFAULT_SIZE: usize = 550;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let mut used = HashSet::from(["in".to_string()]);
+        let mut left = size - 1;
+        let mut todo = VecDeque::from(["in".to_string()]);
+
+        let mut workflows = Vec::new();
+        while let Some(label) = todo.pop_front() {
+            // Jumping only to brand new workflows keeps the workflows from looping
+            let mut verdict = |rng: &mut Rng| match left > 0 && rng.chance(0.5) {
+                true => loop {
+                    let next: String = (0..rng.range(2..=3))
+                        .map(|_| (b'a' + rng.below(26) as u8) as char)
+                        .collect();
+                    if used.insert(next.clone()) {
+                        left -= 1;
+                        todo.push_back(next.clone());
+                        break next;
+                    }
+                },
+                false => rng.pick(&["A", "R"]).to_string(),
+            };
+
+            let mut rules: Vec<String> = (0..rng.range(1..=3))
+                .map(|_| {
+                    let category = rng.pick(&CATEGORIES);
+                    let operator = rng.pick(&['<', '>']);
+                    let value = rng.range(1..=4000);
+                    format!("{}{}{}:{}", category, operator, value, verdict(rng))
+                })
+                .collect();
+            rules.push(verdict(rng));
+            workflows.push(format!("{}{{{}}}", label, rules.join(",")));
+        }
+        rng.shuffle(&mut workflows);
+
+        let parts: Vec<String> = (0..(size / 3).max(1))
+            .map(|_| {
+                let ratings: Vec<String> = CATEGORIES
+                    .iter()
+                    .map(|category| format!("{}={}", category, rng.range(1..=4000)))
+                    .collect();
+                format!("{{{}}}", ratings.join(","))
+            })
+            .collect();
+
+        format!("{}\n\n{}\n", workflows.join("\n"), parts.join("\n"))
+    }
+}
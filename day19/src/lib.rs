@@ -1,5 +1,9 @@
+use common::parse::{sections, Line};
+use common::{Answer, ParseError, Solution};
 use std::collections::{BTreeSet, HashMap};
 
+mod generate;
+
 pub type PuzzleInput = (HashMap<String, Workflow>, Vec<Point>);
 
 #[derive(Default, Debug)]
@@ -73,14 +77,13 @@ impl Rule {
 }
 
 pub struct Workflow {
-    label: Label,
     rules: Vec<Rule>,
 }
 
-pub fn part1(input: &PuzzleInput) -> usize {
+pub fn part1(input: &PuzzleInput) -> u64 {
     let (workflows, points) = input;
 
-    let mut ret = 0usize;
+    let mut ret = 0u64;
     for point in points {
         let mut cur_label = "in".to_string();
         'eval: loop {
@@ -89,7 +92,7 @@ pub fn part1(input: &PuzzleInput) -> usize {
                 if rule.eval(point) {
                     match &rule.verdict {
                         Verdict::Accepted => {
-                            ret += point.value() as usize;
+                            ret += point.value() as u64;
                             break 'eval;
                         }
                         Verdict::Rejected => {
@@ -118,8 +121,8 @@ impl Default for Range {
     }
 }
 impl Range {
-    fn size(&self) -> usize {
-        i32::max(0, self.e - self.s + 1) as usize
+    fn size(&self) -> u64 {
+        i32::max(0, self.e - self.s + 1) as u64
     }
 }
 #[derive(Default, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Debug)]
@@ -128,7 +131,7 @@ struct PointRange {
 }
 
 impl PointRange {
-    fn value(&self) -> usize {
+    fn value(&self) -> u64 {
         self.xmas.iter().map(Range::size).product()
     }
 
@@ -166,7 +169,7 @@ impl PointRange {
     }
 }
 
-pub fn part2(input: &PuzzleInput) -> usize {
+pub fn part2(input: &PuzzleInput) -> u64 {
     let (workflows, _) = input;
     let mut ret = 0;
 
@@ -233,87 +236,208 @@ pub fn part2(input: &PuzzleInput) -> usize {
     ret
 }
 
-fn parse_verdict(v: &str) -> Verdict {
+fn is_label(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_lowercase())
+}
+
+fn parse_verdict<'a>(line: &Line<'a>, v: &'a str) -> Result<Verdict, ParseError> {
     match v {
-        "A" => Verdict::Accepted,
-        "R" => Verdict::Rejected,
-        x => Verdict::GoTo(x.to_string()),
+        "A" => Ok(Verdict::Accepted),
+        "R" => Ok(Verdict::Rejected),
+        x if is_label(x) => Ok(Verdict::GoTo(x.to_string())),
+        x => Err(line.error(x, "expected 'A', 'R' or a workflow label")),
     }
 }
 
-pub fn read_input(filename: &str) -> PuzzleInput {
-    let data = std::fs::read_to_string(filename).unwrap();
+fn parse_token<'a>(line: &Line<'a>, token: &'a str) -> Result<XMAS, ParseError> {
+    match token {
+        "x" => Ok(XMAS::X),
+        "m" => Ok(XMAS::M),
+        "a" => Ok(XMAS::A),
+        "s" => Ok(XMAS::S),
+        _ => Err(line.error(token, "expected 'x', 'm', 'a' or 's'")),
+    }
+}
 
-    let (workflows, points) = data.split_once("\n\n").unwrap();
-    let workflows = workflows
-        .lines()
-        .map(|line| {
-            let (label, tmp) = line.split_once('{').unwrap();
-            let workflow = tmp.split_once('}').unwrap().0;
-
-            let rules: Vec<Rule> = workflow
-                .split(',')
-                .map(|rule| {
-                    let (predicate, verdict) = match rule.contains(':') {
-                        false => (None, parse_verdict(rule)),
-                        true => {
-                            let (tmp, label) = rule.split_once(':').unwrap();
-                            let operator = match tmp.contains('<') {
-                                true => Operator::LessThan,
-                                false => Operator::GreaterThan,
-                            };
-                            let (token, value) = tmp.split_once(['<', '>']).unwrap();
-                            let token = match token {
-                                "x" => XMAS::X,
-                                "m" => XMAS::M,
-                                "a" => XMAS::A,
-                                "s" => XMAS::S,
-                                _ => panic!("invalid token"),
-                            };
-                            let value = value.parse::<i32>().unwrap();
-
-                            (
-                                Some(Predicate {
-                                    token,
-                                    operator,
-                                    value,
-                                }),
-                                parse_verdict(label),
-                            )
-                        }
-                    };
+fn parse_rule<'a>(line: &Line<'a>, rule: &'a str) -> Result<Rule, ParseError> {
+    let (predicate, verdict) = match rule.split_once(':') {
+        None => (None, parse_verdict(line, rule)?),
+        Some((tmp, label)) => {
+            let operator = match tmp.contains('<') {
+                true => Operator::LessThan,
+                false => Operator::GreaterThan,
+            };
+            let (token, value) = tmp
+                .split_once(['<', '>'])
+                .ok_or_else(|| line.error(tmp, "expected a condition like 'x<10'"))?;
+            let token = parse_token(line, token)?;
+            // Small enough to step past in either direction without overflowing
+            let value = line.parse::<u16>(value, "a threshold")? as i32;
+
+            (
+                Some(Predicate {
+                    token,
+                    operator,
+                    value,
+                }),
+                parse_verdict(line, label)?,
+            )
+        }
+    };
 
-                    Rule { predicate, verdict }
-                })
-                .collect();
+    Ok(Rule { predicate, verdict })
+}
 
-            let workflow = Workflow {
-                label: label.to_string(),
-                rules,
-            };
-            (label.to_string(), workflow)
+/// Parses a line like `px{a<2006:qkq,m>2090:A,rfg}`. The labels of all workflows that are
+/// jumped to are added to `targets`, so the caller can check that they exist.
+fn parse_workflow<'a>(
+    line: &Line<'a>,
+    targets: &mut Vec<(Line<'a>, &'a str)>,
+) -> Result<(Label, Workflow), ParseError> {
+    let (label, tmp) = line.split_once(line.text, "{")?;
+    if !is_label(label) {
+        return Err(line.error(label, "expected a workflow label"));
+    }
+    let workflow = tmp
+        .strip_suffix('}')
+        .ok_or_else(|| line.error_at_end("expected '}'"))?;
+
+    let rules: Vec<Rule> = workflow
+        .split(',')
+        .map(|rule| {
+            let parsed = parse_rule(line, rule)?;
+            if let Verdict::GoTo(_) = parsed.verdict {
+                targets.push((*line, rule.split_once(':').map_or(rule, |(_, label)| label)));
+            }
+            Ok(parsed)
         })
-        .collect::<HashMap<String, Workflow>>();
-
-    let points = points
-        .lines()
-        .map(|line| {
-            let mut point = Point::default();
-            let line = line.split_once('{').unwrap().1.split_once('}').unwrap().0;
-            for group in line.split(',') {
-                let (tok, value) = group.split_once('=').unwrap();
-                let value = value.parse().unwrap();
-                match tok {
-                    "x" => point.x = value,
-                    "m" => point.m = value,
-                    "a" => point.a = value,
-                    "s" => point.s = value,
-                    _ => panic!("Invalid token"),
+        .collect::<Result<_, ParseError>>()?;
+
+    // Without a fallback, a part could fall through every rule and never leave this workflow.
+    if rules.last().is_some_and(|rule| rule.predicate.is_some()) {
+        let last = workflow.rsplit(',').next().unwrap();
+        return Err(line.error(last, "expected the last rule to be unconditional"));
+    }
+
+    Ok((label.to_string(), Workflow { rules }))
+}
+
+/// Finds a jump (as collected by [`parse_workflow`]) that a part coming from "in" can take to a
+/// workflow it has already been through. Such a part would go round in circles forever.
+fn find_loop<'a>(targets: &[(Line<'a>, &'a str)]) -> Option<(Line<'a>, &'a str)> {
+    let mut jumps: HashMap<&str, Vec<(Line<'a>, &'a str)>> = HashMap::new();
+    for &(line, target) in targets {
+        let label = line.text.split('{').next().unwrap();
+        jumps.entry(label).or_default().push((line, target));
+    }
+
+    // Depth first. A workflow is `true` while we're looking at the workflows after it, and `false`
+    // once we know that none of them lead back to it.
+    fn visit<'a>(
+        label: &'a str,
+        jumps: &HashMap<&str, Vec<(Line<'a>, &'a str)>>,
+        on_path: &mut HashMap<&'a str, bool>,
+    ) -> Option<(Line<'a>, &'a str)> {
+        on_path.insert(label, true);
+        for &(line, target) in jumps.get(label).into_iter().flatten() {
+            match on_path.get(target) {
+                Some(true) => return Some((line, target)),
+                Some(false) => {}
+                None => {
+                    if let Some(jump) = visit(target, jumps, on_path) {
+                        return Some(jump);
+                    }
                 }
             }
-            point
-        })
-        .collect();
+        }
+        on_path.insert(label, false);
+        None
+    }
+    visit("in", &jumps, &mut HashMap::new())
+}
 
-    (workflows, points)
+/// Parses a line like `{x=787,m=2655,a=1222,s=2876}`.
+fn parse_point(line: &Line) -> Result<Point, ParseError> {
+    let mut point = Point::default();
+    let ratings = line.strip_prefix(line.text, "{")?;
+    let ratings = ratings
+        .strip_suffix('}')
+        .ok_or_else(|| line.error_at_end("expected '}'"))?;
+    for group in ratings.split(',') {
+        let (tok, value) = line.split_once(group, "=")?;
+        let rating = line.parse(value, "a rating")?;
+        if !(1..=4000).contains(&rating) {
+            return Err(line.error(value, "expected a rating between 1 and 4000"));
+        }
+        match parse_token(line, tok)? {
+            XMAS::X => point.x = rating,
+            XMAS::M => point.m = rating,
+            XMAS::A => point.a = rating,
+            XMAS::S => point.s = rating,
+        }
+    }
+    Ok(point)
+}
+
+pub fn parse(data: &str) -> Result<PuzzleInput, ParseError> {
+    let sections = sections(data);
+    let (workflows, points) = match &sections[..] {
+        [workflows, points] => (workflows, points),
+        [] => return Err(ParseError::new(1, 1, "", "expected a list of workflows")),
+        [workflows] => {
+            let line = workflows.last().unwrap();
+            let message = "expected an empty line, followed by part ratings";
+            return Err(ParseError::new(line.number + 1, 1, "", message));
+        }
+        [_, _, extra, ..] => {
+            let line = extra[0];
+            return Err(line.error(line.text, "expected only workflows and part ratings"));
+        }
+    };
+
+    let mut targets = Vec::new();
+    let workflows = workflows
+        .iter()
+        .map(|line| parse_workflow(line, &mut targets))
+        .collect::<Result<HashMap<Label, Workflow>, ParseError>>()?;
+
+    if !workflows.contains_key("in") {
+        return Err(ParseError::new(1, 1, "", "expected a workflow named 'in'"));
+    }
+    if let Some((line, target)) = targets
+        .iter()
+        .find(|(_, target)| !workflows.contains_key(*target))
+    {
+        return Err(line.error(target, "expected the label of an existing workflow"));
+    }
+    if let Some((line, target)) = find_loop(&targets) {
+        return Err(line.error(target, "expected a jump that doesn't go round in circles"));
+    }
+
+    let points = points.iter().map(parse_point).collect::<Result<_, _>>()?;
+
+    Ok((workflows, points))
+}
+
+#[cfg(feature = "std")]
+pub fn read_input(filename: &str) -> Result<PuzzleInput, common::InputError> {
+    Day19::read_input(filename)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
 }
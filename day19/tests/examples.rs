@@ -0,0 +1,6 @@
+use day19::Day19;
+
+#[test]
+fn examples() {
+    common::fixtures::check::<Day19>(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+}
@@ -0,0 +1,6 @@
+use day19::Day19;
+
+#[test]
+fn generated() {
+    common::generate::check::<Day19>();
+}
@@ -0,0 +1,340 @@
+//! Points, vectors and directions on the 2D plane.
+//!
+//! Like [`Grid`](crate::Grid), `y` grows downwards: [`Direction::Up`] is `(0, -1)`. Days whose
+//! puzzle has `y` growing upwards should flip the sign when parsing, rather than redefining the
+//! directions.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+/// The difference between two points.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vector {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        (other - self).manhattan()
+    }
+
+    /// The neighbouring point in direction `dir`.
+    pub fn step(self, dir: Direction) -> Point {
+        self + dir.vector()
+    }
+
+    /// The point `n` steps away in direction `dir`.
+    pub fn step_n(self, dir: Direction, n: isize) -> Point {
+        self + dir.vector() * n
+    }
+
+    /// The 4 orthogonal neighbours, in the order of [`Direction::ALL`].
+    pub fn neighbours4(self) -> [Point; 4] {
+        Direction::ALL.map(|dir| self.step(dir))
+    }
+
+    /// All 8 neighbours, in the order of [`Direction8::ALL`].
+    pub fn neighbours8(self) -> [Point; 8] {
+        Direction8::ALL.map(|dir| self + dir.vector())
+    }
+}
+
+impl Vector {
+    pub const ZERO: Vector = Vector { x: 0, y: 0 };
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Vector { x, y }
+    }
+
+    /// The length of the vector in steps along the axes.
+    pub fn manhattan(self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+}
+
+impl From<(isize, isize)> for Point {
+    fn from((x, y): (isize, isize)) -> Self {
+        Point { x, y }
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point {
+            x: x as isize,
+            y: y as isize,
+        }
+    }
+}
+
+impl From<Point> for (isize, isize) {
+    fn from(p: Point) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl From<(isize, isize)> for Vector {
+    fn from((x, y): (isize, isize)) -> Self {
+        Vector { x, y }
+    }
+}
+
+impl From<Vector> for (isize, isize) {
+    fn from(v: Vector) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    #[inline]
+    fn add(self, v: Vector) -> Point {
+        Point::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    #[inline]
+    fn add_assign(&mut self, v: Vector) {
+        *self = *self + v;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    #[inline]
+    fn sub(self, v: Vector) -> Point {
+        Point::new(self.x - v.x, self.y - v.y)
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    #[inline]
+    fn sub_assign(&mut self, v: Vector) {
+        *self = *self - v;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    #[inline]
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    #[inline]
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    #[inline]
+    fn sub(self, other: Vector) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    #[inline]
+    fn mul(self, n: isize) -> Vector {
+        Vector::new(self.x * n, self.y * n)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    #[inline]
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+/// One of the 4 orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise, starting at `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The unit vector pointing this way.
+    #[inline]
+    pub const fn vector(self) -> Vector {
+        match self {
+            Direction::Up => Vector::new(0, -1),
+            Direction::Right => Vector::new(1, 0),
+            Direction::Down => Vector::new(0, 1),
+            Direction::Left => Vector::new(-1, 0),
+        }
+    }
+
+    /// The direction a quarter turn counterclockwise.
+    #[inline]
+    pub const fn turn_left(self) -> Self {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    /// The direction a quarter turn clockwise.
+    #[inline]
+    pub const fn turn_right(self) -> Self {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    #[inline]
+    pub const fn reverse(self) -> Self {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Whether this is `Left` or `Right`.
+    pub const fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+}
+
+impl From<Direction> for Vector {
+    fn from(dir: Direction) -> Self {
+        dir.vector()
+    }
+}
+
+/// Fails for anything but a unit vector along one of the axes.
+impl TryFrom<Vector> for Direction {
+    type Error = Vector;
+
+    fn try_from(v: Vector) -> Result<Self, Vector> {
+        Direction::ALL
+            .into_iter()
+            .find(|dir| dir.vector() == v)
+            .ok_or(v)
+    }
+}
+
+/// One of the 8 directions: orthogonal and diagonal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Clockwise, starting at `Up`.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// The vector pointing this way, with both components in `-1..=1`.
+    #[inline]
+    pub const fn vector(self) -> Vector {
+        match self {
+            Direction8::Up => Vector::new(0, -1),
+            Direction8::UpRight => Vector::new(1, -1),
+            Direction8::Right => Vector::new(1, 0),
+            Direction8::DownRight => Vector::new(1, 1),
+            Direction8::Down => Vector::new(0, 1),
+            Direction8::DownLeft => Vector::new(-1, 1),
+            Direction8::Left => Vector::new(-1, 0),
+            Direction8::UpLeft => Vector::new(-1, -1),
+        }
+    }
+
+    /// The direction an eighth turn counterclockwise.
+    #[inline]
+    pub const fn turn_left(self) -> Self {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    /// The direction an eighth turn clockwise.
+    #[inline]
+    pub const fn turn_right(self) -> Self {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    #[inline]
+    pub const fn reverse(self) -> Self {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        Direction8::ALL[dir as usize * 2]
+    }
+}
+
+/// Fails for the diagonal directions.
+impl TryFrom<Direction8> for Direction {
+    type Error = Direction8;
+
+    fn try_from(dir: Direction8) -> Result<Self, Direction8> {
+        match dir as usize % 2 {
+            0 => Ok(Direction::ALL[dir as usize / 2]),
+            _ => Err(dir),
+        }
+    }
+}
+
+impl From<Direction8> for Vector {
+    fn from(dir: Direction8) -> Self {
+        dir.vector()
+    }
+}
+
+/// Fails for anything that is not one step in one of the 8 directions.
+impl TryFrom<Vector> for Direction8 {
+    type Error = Vector;
+
+    fn try_from(v: Vector) -> Result<Self, Vector> {
+        Direction8::ALL
+            .into_iter()
+            .find(|dir| dir.vector() == v)
+            .ok_or(v)
+    }
+}
@@ -0,0 +1,366 @@
+//! A rectangular grid of cells, for the days whose input is a map of some sort.
+//!
+//! Cells are stored flat, in row-major order, and addressed as `(x, y)` with `(0, 0)` in the top
+//! left corner and `y` growing downwards, the same way the input text is laid out. The
+//! [`geometry`] types follow the same convention, and can be used to index the grid as well.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use common::parse::{lines, Line};
+use common::ParseError;
+
+pub mod geometry;
+mod outline;
+mod transposed;
+
+pub use geometry::{Direction, Direction8, Point, Vector};
+pub use outline::outline;
+pub use transposed::Transposed;
+
+/// Offsets to the 4 orthogonal neighbours: up, right, down, left.
+const OFFSETS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets to all 8 neighbours, clockwise starting at the top left.
+const OFFSETS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `width` columns, filled row by row from `cells`.
+    ///
+    /// Panics if `cells` does not hold a whole number of rows.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not make rows of {}",
+            cells.len(),
+            width
+        );
+        Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    /// A grid made up of `rows`.
+    ///
+    /// Panics if the rows are not all the same, non-zero, length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "rows differ in length"
+        );
+        Grid::from_vec(width, rows.into_iter().flatten().collect())
+    }
+
+    /// Parses a non-empty, rectangular grid of characters, mapping each cell through `cell`.
+    ///
+    /// `expected` describes the valid characters, and is used when `cell` rejects one.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        Grid::parse_lines(lines(input), expected, cell)
+    }
+
+    /// Like [`Grid::parse`], for a grid that is only part of the input.
+    pub fn parse_lines<'a>(
+        lines: impl IntoIterator<Item = Line<'a>>,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        for line in lines {
+            let start = cells.len();
+            for (idx, c) in line.text.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    line.error(
+                        &line.text[idx..idx + c.len_utf8()],
+                        format!("expected {}", expected),
+                    )
+                })?;
+                cells.push(value);
+            }
+
+            let len = cells.len() - start;
+            if len == 0 {
+                return Err(line.error_at_end("expected a non-empty row"));
+            }
+            if start == 0 {
+                width = len;
+            }
+            if len != width {
+                return Err(line.error(line.text, format!("expected a row of {} cells", width)));
+            }
+        }
+
+        match cells.is_empty() {
+            true => Err(ParseError::new(1, 1, "", "expected a non-empty grid")),
+            false => Ok(Grid::from_vec(width, cells)),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `(x, y)` lies within the grid.
+    #[inline]
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    #[inline]
+    fn offset(&self, x: usize, y: usize) -> usize {
+        y * self.width + x
+    }
+
+    /// The cell at `(x, y)`, or `None` if that lies outside the grid.
+    #[inline]
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        match self.contains(x, y) {
+            true => Some(&self.cells[self.offset(x as usize, y as usize)]),
+            false => None,
+        }
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        match self.contains(x, y) {
+            true => {
+                let offset = self.offset(x as usize, y as usize);
+                Some(&mut self.cells[offset])
+            }
+            false => None,
+        }
+    }
+
+    /// The cell at `p`, or `None` if that lies outside the grid.
+    #[inline]
+    pub fn get_point(&self, p: Point) -> Option<&T> {
+        self.get(p.x, p.y)
+    }
+
+    #[inline]
+    pub fn get_point_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.get_mut(p.x, p.y)
+    }
+
+    /// The cell at `(x, y)`, treating the grid as if it repeats infinitely in every direction.
+    #[inline]
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self.cells[self.offset(x, y)]
+    }
+
+    /// All cells, row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn enumerate(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position of the first cell (row by row) matching `pred`.
+    pub fn position(&self, pred: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        let offset = self.cells.iter().position(pred)?;
+        Some((offset % self.width, offset / self.width))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> std::slice::ChunksExact<'_, T> {
+        self.cells.chunks_exact(self.width)
+    }
+
+    pub fn rows_mut(&mut self) -> std::slice::ChunksExactMut<'_, T> {
+        self.cells.chunks_exact_mut(self.width)
+    }
+
+    /// The cells in column `x`, top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {} out of bounds", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// A view of the grid with rows and columns swapped, without copying it.
+    pub fn transposed(&self) -> Transposed<'_, T> {
+        Transposed::new(self)
+    }
+
+    /// The positions of the (up to 4) orthogonal neighbours of `(x, y)` that lie within the grid.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &OFFSETS4)
+    }
+
+    /// The positions of the (up to 8) neighbours of `(x, y)`, including diagonals, that lie within
+    /// the grid.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &OFFSETS8)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let (nx, ny) = (x as isize + dx, y as isize + dy);
+            self.contains(nx, ny).then_some((nx as usize, ny as usize))
+        })
+    }
+
+    /// A grid of the same size, with every cell mapped through `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid::from_vec(width, vec![fill; width * height])
+    }
+
+    /// A copy of the grid with rows and columns swapped.
+    pub fn transpose(&self) -> Self {
+        Grid::from_vec(
+            self.height,
+            self.columns().flat_map(|column| column.cloned()).collect(),
+        )
+    }
+
+    /// A copy of the grid, rotated a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Self {
+        Grid::from_vec(
+            self.height,
+            self.columns()
+                .flat_map(|column| column.rev().cloned())
+                .collect(),
+        )
+    }
+
+    /// A copy of the grid, rotated a quarter turn counterclockwise.
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Grid::from_vec(
+            self.height,
+            (0..self.width)
+                .rev()
+                .flat_map(|x| self.column(x).cloned())
+                .collect(),
+        )
+    }
+}
+
+/// Panics if `(x, y)` lies outside the grid.
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) out of bounds"
+        );
+        &self.cells[self.offset(x, y)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    #[inline]
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) out of bounds"
+        );
+        let offset = self.offset(x, y);
+        &mut self.cells[offset]
+    }
+}
+
+/// Panics if `p` lies outside the grid.
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, p: Point) -> &T {
+        self.get_point(p)
+            .unwrap_or_else(|| panic!("{:?} out of bounds", p))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    #[inline]
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        self.get_point_mut(p)
+            .unwrap_or_else(|| panic!("{:?} out of bounds", p))
+    }
+}
+
+/// Prints the grid the way it would appear in the input, one row per line.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+//! Random closed loops on the lattice, for generating inputs.
+
+use common::Rng;
+
+/// A random simple loop on the lattice, for the days whose input describes one (pipes, a dig
+/// plan). It is the outline of a random tree, drawn with corridors one cell wide on a grid of
+/// `2 * width - 1` by `2 * height - 1` cells, so it winds through all of that grid.
+///
+/// Returns the corners of the cells it passes, in clockwise order (with y pointing down), each one
+/// step away from the previous one; the last one is next to the first. All coordinates are at most
+/// `2 * width - 1` and `2 * height - 1`.
+pub fn outline(rng: &mut Rng, width: usize, height: usize) -> Vec<(usize, usize)> {
+    let (width, height) = (width.max(1), height.max(1));
+    let (cols, rows) = (2 * width - 1, 2 * height - 1);
+    let mut filled = vec![false; cols * rows];
+
+    // Randomized Prim: tree nodes sit at even coordinates, and an edge fills the cell between two
+    // of them. Corridors never touch, so the outline can't pinch or enclose a hole.
+    let start = (2 * rng.below(width), 2 * rng.below(height));
+    filled[start.1 * cols + start.0] = true;
+    let mut frontier = vec![start];
+    while !frontier.is_empty() {
+        let idx = rng.below(frontier.len());
+        let (x, y) = frontier[idx];
+        let options: Vec<(usize, usize)> = [(0, -2), (2, 0), (0, 2), (-2, 0)]
+            .into_iter()
+            .filter_map(|(dx, dy)| {
+                let nx = x.checked_add_signed(dx).filter(|&nx| nx < cols)?;
+                let ny = y.checked_add_signed(dy).filter(|&ny| ny < rows)?;
+                (!filled[ny * cols + nx]).then_some((nx, ny))
+            })
+            .collect();
+        if options.is_empty() {
+            frontier.swap_remove(idx);
+            continue;
+        }
+        let (nx, ny) = *rng.pick(&options);
+        filled[ny * cols + nx] = true;
+        filled[(y + ny) / 2 * cols + (x + nx) / 2] = true;
+        frontier.push((nx, ny));
+    }
+
+    // Every side of a filled cell that borders an empty one is part of the outline. Walking each
+    // cell clockwise means every corner has exactly one side leaving it.
+    let is_filled = |x: usize, y: usize| x < cols && y < rows && filled[y * cols + x];
+    let corners = cols + 1;
+    let mut next = vec![None; corners * (rows + 1)];
+    for y in 0..rows {
+        for x in (0..cols).filter(|&x| is_filled(x, y)) {
+            let sides = [
+                (y == 0 || !is_filled(x, y - 1), (x, y), (x + 1, y)),
+                (!is_filled(x + 1, y), (x + 1, y), (x + 1, y + 1)),
+                (!is_filled(x, y + 1), (x + 1, y + 1), (x, y + 1)),
+                (x == 0 || !is_filled(x - 1, y), (x, y + 1), (x, y)),
+            ];
+            for (_, from, to) in sides.into_iter().filter(|side| side.0) {
+                next[from.1 * corners + from.0] = Some(to);
+            }
+        }
+    }
+
+    // The start node's top left corner is on the outline, like every corner of a filled cell
+    let mut path = vec![start];
+    loop {
+        let (x, y) = path[path.len() - 1];
+        let corner = next[y * corners + x].expect("the outline is a single loop");
+        if corner == start {
+            return path;
+        }
+        path.push(corner);
+    }
+}
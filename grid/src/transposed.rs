@@ -0,0 +1,53 @@
+//! A borrowed view of a grid with rows and columns swapped.
+
+use std::ops::Index;
+
+use crate::Grid;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Transposed<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T> Transposed<'a, T> {
+    pub(crate) fn new(grid: &'a Grid<T>) -> Self {
+        Transposed { grid }
+    }
+
+    pub fn width(&self) -> usize {
+        self.grid.height()
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid.width()
+    }
+
+    #[inline]
+    pub fn get(&self, x: isize, y: isize) -> Option<&'a T> {
+        self.grid.get(y, x)
+    }
+
+    /// The cells in row `y` of the view, i.e. column `y` of the grid.
+    pub fn row(&self, y: usize) -> impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator {
+        self.grid.column(y)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &'a T>> {
+        let grid = self.grid;
+        (0..grid.width()).map(move |x| grid.column(x))
+    }
+
+    /// The cells in column `x` of the view, i.e. row `x` of the grid.
+    pub fn column(&self, x: usize) -> &'a [T] {
+        self.grid.row(x)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Transposed<'_, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        &self.grid[(y, x)]
+    }
+}
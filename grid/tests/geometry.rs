@@ -0,0 +1,61 @@
+use grid::{Direction, Direction8, Grid, Point, Vector};
+
+#[test]
+fn y_grows_downwards() {
+    assert_eq!(Direction::Up.vector(), Vector::new(0, -1));
+    assert_eq!(Direction8::DownLeft.vector(), Vector::new(-1, 1));
+
+    // Stepping up from the bottom row ends up one row higher in the grid
+    let grid = Grid::parse("a\nb\n", "a letter", Some).unwrap();
+    assert_eq!(grid[Point::new(0, 1).step(Direction::Up)], 'a');
+}
+
+#[test]
+fn turning() {
+    for dir in Direction::ALL {
+        assert_eq!(dir.turn_left().turn_right(), dir);
+        assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+        assert_eq!(dir.reverse().vector(), -dir.vector());
+    }
+    assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    assert_eq!(Direction::Up.turn_left(), Direction::Left);
+
+    for dir in Direction8::ALL {
+        assert_eq!(dir.turn_left().turn_right(), dir);
+        assert_eq!(dir.reverse().vector(), -dir.vector());
+    }
+    assert_eq!(Direction8::Up.turn_right(), Direction8::UpRight);
+    assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+}
+
+#[test]
+fn conversions() {
+    for dir in Direction::ALL {
+        assert_eq!(Direction::try_from(dir.vector()), Ok(dir));
+        assert_eq!(Direction::try_from(Direction8::from(dir)), Ok(dir));
+    }
+    assert_eq!(
+        Direction::try_from(Direction8::UpRight),
+        Err(Direction8::UpRight)
+    );
+    assert!(Direction::try_from(Vector::new(1, 1)).is_err());
+    assert_eq!(
+        Direction8::try_from(Vector::new(1, 1)),
+        Ok(Direction8::DownRight)
+    );
+
+    assert_eq!(Point::from((3usize, 4usize)), Point::new(3, 4));
+    assert_eq!(<(isize, isize)>::from(Point::new(-1, 2)), (-1, 2));
+}
+
+#[test]
+fn distances() {
+    let a = Point::new(1, -2);
+    let b = Point::new(-3, 5);
+    assert_eq!(b - a, Vector::new(-4, 7));
+    assert_eq!(a + (b - a), b);
+    assert_eq!(a.manhattan(b), 11);
+    assert_eq!(b.manhattan(a), 11);
+    assert_eq!(a.step_n(Direction::Left, 4), Point::new(-3, -2));
+    assert!(a.neighbours8().iter().all(|&p| p.manhattan(a) <= 2));
+}
@@ -0,0 +1,96 @@
+use grid::Grid;
+
+/// ```text
+/// abc
+/// def
+/// ```
+fn letters() -> Grid<char> {
+    Grid::parse("abc\ndef\n", "a letter", |c| {
+        c.is_ascii_lowercase().then_some(c)
+    })
+    .unwrap()
+}
+
+#[test]
+fn parse() {
+    let grid = letters();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(2, 0)], 'c');
+    assert_eq!(grid[(0, 1)], 'd');
+    assert_eq!(grid.to_string(), "abc\ndef\n");
+
+    let err = Grid::parse("ab\nc\n", "a letter", Some).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 1));
+}
+
+#[test]
+fn checked_and_wrapping_access() {
+    let grid = letters();
+    assert_eq!(grid.get(1, 1), Some(&'e'));
+    assert_eq!(grid.get(3, 0), None);
+    assert_eq!(grid.get(-1, 0), None);
+    assert_eq!(grid.get(0, 2), None);
+    assert_eq!(*grid.get_wrapping(3, 0), 'a');
+    assert_eq!(*grid.get_wrapping(-1, -1), 'f');
+    assert_eq!(*grid.get_wrapping(-4, 5), 'f');
+}
+
+#[test]
+#[should_panic(expected = "(3, 0) out of bounds")]
+fn index_past_the_end_of_a_row_panics() {
+    // Rather than wrapping round to the 'd' at the start of the next row
+    assert_ne!(letters()[(3, 0)], 'd');
+}
+
+#[test]
+fn rows_and_columns() {
+    let grid = letters();
+    assert_eq!(grid.row(1), ['d', 'e', 'f']);
+    assert_eq!(grid.column(1).collect::<String>(), "be");
+    assert_eq!(
+        grid.columns()
+            .map(|column| column.collect::<String>())
+            .collect::<Vec<_>>(),
+        ["ad", "be", "cf"]
+    );
+    assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+}
+
+#[test]
+fn transposed() {
+    let grid = letters();
+    let view = grid.transposed();
+    assert_eq!((view.width(), view.height()), (2, 3));
+    assert_eq!(view[(1, 0)], 'd');
+    assert_eq!(view.get(0, 2), Some(&'c'));
+    assert_eq!(view.row(2).collect::<String>(), "cf");
+
+    let owned = grid.transpose();
+    assert_eq!(owned.to_string(), "ad\nbe\ncf\n");
+    assert_eq!(owned.transpose(), grid);
+}
+
+#[test]
+fn rotation() {
+    let grid = letters();
+    assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+    assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+
+    let mut turned = grid.clone();
+    for _ in 0..4 {
+        turned = turned.rotate_clockwise();
+    }
+    assert_eq!(turned, grid);
+}
+
+#[test]
+fn neighbours() {
+    let grid = Grid::new(3, 3, 0);
+    assert_eq!(grid.neighbours4(1, 1).count(), 4);
+    assert_eq!(grid.neighbours8(1, 1).count(), 8);
+    assert_eq!(grid.neighbours4(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+    assert_eq!(
+        grid.neighbours8(2, 2).collect::<Vec<_>>(),
+        [(1, 1), (2, 1), (1, 2)]
+    );
+}
@@ -0,0 +1,27 @@
+use std::collections::HashSet;
+
+use common::Rng;
+use grid::outline;
+
+#[test]
+fn outline_is_a_simple_clockwise_loop() {
+    for seed in 0..20 {
+        for (width, height) in [(1, 1), (1, 4), (5, 1), (6, 9), (0, 0)] {
+            let path = outline(&mut Rng::new(seed), width, height);
+            let unique: HashSet<_> = path.iter().collect();
+            assert_eq!(unique.len(), path.len(), "the loop crosses itself");
+
+            let mut area = 0;
+            for (i, &(x1, y1)) in path.iter().enumerate() {
+                let (x2, y2) = path[(i + 1) % path.len()];
+                assert_eq!(x1.abs_diff(x2) + y1.abs_diff(y2), 1, "not a single step");
+                assert!(x1 < 2 * width.max(1) && y1 < 2 * height.max(1));
+                area += x1 as isize * y2 as isize - x2 as isize * y1 as isize;
+            }
+            // Positive with y pointing down means clockwise on screen. The tree fills every node
+            // and the edges between them, which is 2 * nodes - 1 cells.
+            let nodes = (width.max(1) * height.max(1)) as isize;
+            assert_eq!(area / 2, 2 * nodes - 1);
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Extended Euclid, modular inverses and the Chinese Remainder Theorem.
+
+use std::fmt;
+
+use crate::{checked_gcd, Integer, Signed};
+
+/// Finds `(g, x, y)` such that `a * x + b * y = g`, where `g` is the (non-negative) gcd of `a`
+/// and `b`.
+///
+/// Returns `None` if any of them, or any step on the way (such as `MIN / -1`), does not fit in
+/// `T`.
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> Option<(T, T, T)> {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let q = old_r.checked_div(r)?;
+        (old_r, r) = (r, old_r.checked_sub(q.checked_mul(r)?)?);
+        (old_x, x) = (x, old_x.checked_sub(q.checked_mul(x)?)?);
+        (old_y, y) = (y, old_y.checked_sub(q.checked_mul(y)?)?);
+    }
+    match old_r < T::ZERO {
+        true => Some((
+            old_r.checked_abs()?,
+            T::ZERO.checked_sub(old_x)?,
+            T::ZERO.checked_sub(old_y)?,
+        )),
+        false => Some((old_r, old_x, old_y)),
+    }
+}
+
+/// The `x` in `0..modulus` for which `a * x ≡ 1 (mod modulus)`, or `None` if `a` and `modulus`
+/// are not coprime.
+///
+/// Panics if `modulus` is not positive.
+pub fn mod_inverse<T: Signed>(a: T, modulus: T) -> Option<T> {
+    assert!(modulus > T::ZERO, "modulus {:?} is not positive", modulus);
+    let a = a.checked_rem_euclid(modulus)?;
+    // Both are in `0..=modulus`, so the coefficients can't overflow
+    match extended_gcd(a, modulus)? {
+        (g, x, _) if g == T::ONE => x.checked_rem_euclid(modulus),
+        _ => None,
+    }
+}
+
+/// `a + b (mod m)` for `a` and `b` in `0..m`, without overflowing.
+fn add_mod<T: Integer>(a: T, b: T, m: T) -> T {
+    match a >= m - b {
+        true => a - (m - b),
+        false => a + b,
+    }
+}
+
+/// `a * b (mod m)` for `a` and `b` in `0..m`, without overflowing.
+fn mul_mod<T: Integer>(a: T, b: T, m: T) -> T {
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+
+    // Double and add, so nothing gets bigger than `m`
+    let two = T::ONE + T::ONE;
+    let (mut a, mut b, mut result) = (a, b, T::ZERO);
+    while b > T::ZERO {
+        if b % two == T::ONE {
+            result = add_mod(result, a, m);
+        }
+        a = add_mod(a, a, m);
+        b = b / two;
+    }
+    result
+}
+
+/// `x ≡ residue (mod modulus)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Congruence<T> {
+    /// Always in `0..modulus`.
+    pub residue: T,
+    pub modulus: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    /// The congruences contradict each other.
+    NoSolution,
+    /// The combined modulus does not fit in the integer type.
+    Overflow,
+}
+
+impl fmt::Display for CrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrtError::NoSolution => write!(f, "the congruences have no common solution"),
+            CrtError::Overflow => write!(f, "the combined modulus overflows"),
+        }
+    }
+}
+
+impl std::error::Error for CrtError {}
+
+impl<T: Signed> Congruence<T> {
+    /// `x ≡ residue (mod modulus)`, with `residue` reduced into `0..modulus`.
+    ///
+    /// Panics if `modulus` is not positive.
+    pub fn new(residue: T, modulus: T) -> Self {
+        assert!(modulus > T::ZERO, "modulus {:?} is not positive", modulus);
+        Congruence {
+            residue: residue
+                .checked_rem_euclid(modulus)
+                .expect("a positive modulus does not overflow"),
+            modulus,
+        }
+    }
+
+    /// The congruence that holds exactly when both `self` and `other` do. The moduli do not have
+    /// to be coprime.
+    pub fn merge(self, other: Self) -> Result<Self, CrtError> {
+        let (a1, m1) = (self.residue, self.modulus);
+        let (a2, m2) = (other.residue, other.modulus);
+        let g = checked_gcd(m1, m2).expect("the gcd of positive numbers does not overflow");
+
+        // x = a1 + m1 * k, where m1 * k ≡ a2 - a1 (mod m2), which needs g to divide a2 - a1
+        let diff = a2 - a1;
+        if diff % g != T::ZERO {
+            return Err(CrtError::NoSolution);
+        }
+        let modulus = (m1 / g).checked_mul(m2).ok_or(CrtError::Overflow)?;
+
+        let m2g = m2 / g;
+        let inverse = mod_inverse(m1 / g, m2g).expect("m1 / g and m2 / g are coprime");
+        let diff = (diff / g)
+            .checked_rem_euclid(m2g)
+            .expect("a positive modulus does not overflow");
+        let k = mul_mod(diff, inverse, m2g);
+        // Both terms add up to less than `modulus`, which fits
+        Ok(Congruence {
+            residue: a1 + m1 * k,
+            modulus,
+        })
+    }
+}
+
+/// Solves a system of congruences, returning the combined congruence that holds exactly for the
+/// solutions of all of them. The moduli do not have to be coprime.
+///
+/// An empty system is `x ≡ 0 (mod 1)`, which every integer satisfies.
+pub fn crt<T: Signed>(
+    congruences: impl IntoIterator<Item = Congruence<T>>,
+) -> Result<Congruence<T>, CrtError> {
+    congruences
+        .into_iter()
+        .try_fold(Congruence::new(T::ZERO, T::ONE), Congruence::merge)
+}
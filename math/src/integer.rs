@@ -0,0 +1,89 @@
+//! The operations the algorithms need from an integer type, implemented for the built-in ones.
+
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    /// The quotient, which is `None` for a zero divisor and for `MIN / -1` of a signed type.
+    fn checked_div(self, other: Self) -> Option<Self>;
+    fn checked_rem(self, other: Self) -> Option<Self>;
+    /// The absolute value, which is `None` for `MIN` of a signed type.
+    fn checked_abs(self) -> Option<Self>;
+    /// The remainder of dividing by `modulus`, in `0..modulus.abs()`.
+    fn checked_rem_euclid(self, modulus: Self) -> Option<Self>;
+}
+
+/// Integers that can be negative, as needed by extended Euclid and everything built on it.
+pub trait Signed: Integer + Neg<Output = Self> {}
+
+macro_rules! impl_integer {
+    ($($t:ty),*; abs: $abs:expr) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                #[inline]
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                #[inline]
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+
+                #[inline]
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+
+                #[inline]
+                fn checked_div(self, other: Self) -> Option<Self> {
+                    <$t>::checked_div(self, other)
+                }
+
+                #[inline]
+                fn checked_rem(self, other: Self) -> Option<Self> {
+                    <$t>::checked_rem(self, other)
+                }
+
+                #[inline]
+                fn checked_abs(self) -> Option<Self> {
+                    let abs: fn($t) -> Option<$t> = $abs;
+                    abs(self)
+                }
+
+                #[inline]
+                fn checked_rem_euclid(self, modulus: Self) -> Option<Self> {
+                    <$t>::checked_rem_euclid(self, modulus)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize; abs: Some);
+impl_integer!(i8, i16, i32, i64, i128, isize; abs: |x| x.checked_abs());
+
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for i128 {}
+impl Signed for isize {}
@@ -0,0 +1,56 @@
+//! Number theory for the puzzles that need it: gcd and lcm, extended Euclid, modular inverses and
+//! the Chinese Remainder Theorem.
+//!
+//! Everything is generic over the built-in integer types and checks for overflow: the `checked_`
+//! functions return `None` where the plain ones panic.
+
+mod crt;
+mod integer;
+
+pub use crt::{crt, extended_gcd, mod_inverse, Congruence, CrtError};
+pub use integer::{Integer, Signed};
+
+/// The greatest common divisor of `a` and `b`, which is never negative. `gcd(0, 0)` is 0.
+///
+/// Returns `None` if the result does not fit in `T`, which can only happen for `T::MIN` of a
+/// signed type.
+pub fn checked_gcd<T: Integer>(a: T, b: T) -> Option<T> {
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        // `MIN % -1` overflows, even though the remainder is 0
+        let r = a.checked_rem(b).unwrap_or(T::ZERO);
+        (a, b) = (b, r);
+    }
+    a.checked_abs()
+}
+
+/// Like [`checked_gcd`], but panics if the result does not fit in `T`.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    checked_gcd(a, b).unwrap_or_else(|| panic!("gcd({:?}, {:?}) overflows", a, b))
+}
+
+/// The least common multiple of `a` and `b`, which is never negative. It is 0 if either of them
+/// is.
+///
+/// Returns `None` if the result does not fit in `T`.
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    let g = checked_gcd(a, b)?;
+    (a / g).checked_mul(b)?.checked_abs()
+}
+
+/// Like [`checked_lcm`], but panics if the result does not fit in `T`.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    checked_lcm(a, b).unwrap_or_else(|| panic!("lcm({:?}, {:?}) overflows", a, b))
+}
+
+/// The least common multiple of all `values`, or 1 if there are none.
+///
+/// Returns `None` if the result does not fit in `T`.
+pub fn checked_lcm_all<T: Integer>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values
+        .into_iter()
+        .try_fold(T::ONE, |acc, value| checked_lcm(acc, value))
+}
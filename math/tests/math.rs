@@ -0,0 +1,102 @@
+use math::{
+    checked_gcd, checked_lcm, checked_lcm_all, crt, extended_gcd, gcd, lcm, mod_inverse,
+    Congruence, CrtError,
+};
+
+#[test]
+fn gcd_and_lcm() {
+    assert_eq!(gcd(12u32, 18), 6);
+    assert_eq!(gcd(0u32, 7), 7);
+    assert_eq!(gcd(0u32, 0), 0);
+    assert_eq!(gcd(-12i64, 18), 6);
+    assert_eq!(gcd(12i64, -18), 6);
+    assert_eq!(lcm(4usize, 6), 12);
+    assert_eq!(lcm(-4i32, 6), 12);
+    assert_eq!(lcm(0u8, 6), 0);
+    assert_eq!(checked_lcm_all([2u64, 3, 4, 5]), Some(60));
+    assert_eq!(checked_lcm_all(Vec::<u64>::new()), Some(1));
+}
+
+#[test]
+fn overflow() {
+    assert_eq!(checked_lcm(200u8, 3), None);
+    assert_eq!(checked_lcm(128u8, 2), Some(128));
+    assert_eq!(checked_gcd(i8::MIN, 0), None);
+    assert_eq!(checked_gcd(i8::MIN, i8::MIN), None);
+    assert_eq!(checked_gcd(i8::MIN, 6), Some(2));
+    assert_eq!(checked_gcd(i8::MIN, -1), Some(1));
+    assert_eq!(extended_gcd(i8::MIN, -1), None);
+    assert_eq!(extended_gcd(i64::MIN, -1), None);
+    assert_eq!(checked_lcm_all([u64::MAX, 2]), None);
+}
+
+#[test]
+#[should_panic(expected = "overflows")]
+fn lcm_panics_on_overflow() {
+    lcm(200u8, 3);
+}
+
+#[test]
+fn extended_gcd_identity() {
+    for a in -30i32..=30 {
+        for b in -30i32..=30 {
+            let (g, x, y) = extended_gcd(a, b).unwrap();
+            assert_eq!(g, gcd(a, b), "gcd({}, {})", a, b);
+            assert_eq!(a * x + b * y, g, "{} * {} + {} * {}", a, x, b, y);
+        }
+    }
+}
+
+#[test]
+fn mod_inverse_matches_brute_force() {
+    for m in 1i64..=40 {
+        for a in -40i64..=40 {
+            let brute = (0..m).find(|x| (a * x).rem_euclid(m) == 1 % m);
+            assert_eq!(mod_inverse(a, m), brute, "inverse of {} mod {}", a, m);
+        }
+    }
+}
+
+#[test]
+fn crt_matches_brute_force() {
+    // Includes plenty of moduli that are not coprime
+    for m1 in 1i32..=12 {
+        for m2 in 1i32..=12 {
+            for a1 in 0..m1 {
+                for a2 in 0..m2 {
+                    let l = lcm(m1, m2);
+                    let brute = (0..l).find(|x| x % m1 == a1 && x % m2 == a2);
+                    let solved = crt([Congruence::new(a1, m1), Congruence::new(a2, m2)]);
+                    match brute {
+                        Some(x) => assert_eq!(solved, Ok(Congruence::new(x, l))),
+                        None => assert_eq!(solved, Err(CrtError::NoSolution)),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn crt_large_moduli() {
+    // The intermediate products don't fit in an i64, but the combined modulus does
+    let (m1, m2) = (3i64, (1 << 61) - 1);
+    let (a1, a2) = (2, (1 << 60) + 12345);
+    let solved = crt([Congruence::new(a1, m1), Congruence::new(a2, m2)]).unwrap();
+    assert_eq!(solved.modulus, m1 * m2);
+    assert_eq!(solved.residue % m1, a1);
+    assert_eq!(solved.residue % m2, a2);
+
+    let too_big = crt([Congruence::new(0, m2), Congruence::new(1, m2 - 2)]);
+    assert_eq!(too_big, Err(CrtError::Overflow));
+}
+
+#[test]
+fn crt_empty_and_negative_residues() {
+    assert_eq!(crt::<i64>([]), Ok(Congruence::new(0, 1)));
+    assert_eq!(Congruence::new(-1i64, 5).residue, 4);
+    assert_eq!(
+        crt([Congruence::new(-1i64, 4), Congruence::new(-1, 6)]),
+        Ok(Congruence::new(11, 12))
+    );
+}
@@ -0,0 +1,117 @@
+//! Encoding frames as PNG and GIF.
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use grid::Grid;
+
+use crate::{Animation, Color};
+
+/// The pixels of `frame` with every cell blown up to `scale` by `scale`, row by row.
+fn pixels<T: Copy>(frame: &Grid<T>, scale: usize) -> Vec<T> {
+    let mut pixels = Vec::with_capacity(frame.cells().len() * scale * scale);
+    for row in frame.rows() {
+        for _ in 0..scale {
+            for &cell in row {
+                pixels.extend(std::iter::repeat_n(cell, scale));
+            }
+        }
+    }
+    pixels
+}
+
+/// The size of `frame` in pixels, if it isn't too large for `limit`.
+fn size(frame: &Grid<Color>, scale: usize, limit: usize) -> io::Result<(usize, usize)> {
+    let scale = scale.max(1);
+    match (
+        frame.width().checked_mul(scale),
+        frame.height().checked_mul(scale),
+    ) {
+        (Some(width), Some(height)) if width <= limit && height <= limit => Ok((width, height)),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "a {}x{} frame at scale {} is too large",
+                frame.width(),
+                frame.height(),
+                scale
+            ),
+        )),
+    }
+}
+
+pub(crate) fn png(out: impl Write, frame: &Grid<Color>, scale: usize) -> io::Result<()> {
+    let (width, height) = size(frame, scale, u32::MAX as usize)?;
+    let mut encoder = png::Encoder::new(out, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let data: Vec<u8> = pixels(frame, scale.max(1))
+        .into_iter()
+        .flat_map(|color| [color.r, color.g, color.b])
+        .collect();
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    writer.write_image_data(&data).map_err(io::Error::other)?;
+    writer.finish().map_err(io::Error::other)
+}
+
+pub(crate) fn gif(out: impl Write, animation: &Animation, scale: usize) -> io::Result<()> {
+    let Some(first) = animation.frames().first() else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "an animation needs at least one frame",
+        ));
+    };
+    let (width, height) = size(first, scale, u16::MAX as usize)?;
+    let scale = scale.max(1);
+    // GIF delays are in hundredths of a second
+    let delay = (animation.delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+
+    // The puzzles only use a handful of colors, which all fit in a single global palette. Should
+    // they not, every frame gets a palette of its own, picked by quantizing it.
+    let mut palette: HashMap<Color, u8> = HashMap::new();
+    for &color in animation.frames().iter().flat_map(|frame| frame.iter()) {
+        if palette.len() > 256 {
+            break;
+        }
+        let idx = palette.len();
+        palette.entry(color).or_insert(idx as u8);
+    }
+    let global = palette.len() <= 256;
+
+    let mut global_palette = Vec::new();
+    if global {
+        let mut colors: Vec<(&Color, &u8)> = palette.iter().collect();
+        colors.sort_by_key(|&(_, &idx)| idx);
+        global_palette = colors
+            .into_iter()
+            .flat_map(|(color, _)| [color.r, color.g, color.b])
+            .collect();
+    }
+    let mut encoder = gif::Encoder::new(out, width as u16, height as u16, &global_palette)
+        .map_err(io::Error::other)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(io::Error::other)?;
+
+    for frame in animation.frames() {
+        let mut gif_frame = match global {
+            true => {
+                let indices: Vec<u8> = pixels(frame, scale)
+                    .into_iter()
+                    .map(|color| palette[&color])
+                    .collect();
+                gif::Frame::from_indexed_pixels(width as u16, height as u16, indices, None)
+            }
+            false => {
+                let data: Vec<u8> = pixels(frame, scale)
+                    .into_iter()
+                    .flat_map(|color| [color.r, color.g, color.b])
+                    .collect();
+                gif::Frame::from_rgb_speed(width as u16, height as u16, &data, 10)
+            }
+        };
+        gif_frame.delay = delay;
+        encoder.write_frame(&gif_frame).map_err(io::Error::other)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,123 @@
+//! Pictures of the grid puzzles, as PNG images and animated GIFs, for write-ups and debugging.
+//!
+//! A frame is a [`Grid`] of [`Color`]s with one cell per tile, which is drawn as a square of
+//! `scale` by `scale` pixels. Days that can be drawn implement [`Render`] in their
+//! `src/render.rs`, and `aoc render` writes the result to a file.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use common::Solution;
+use grid::Grid;
+
+mod encode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color { r, g, b }
+    }
+
+    /// The color a fraction `t` (from 0 to 1) of the way from `self` to `other`.
+    pub fn mix(self, other: Color, t: f64) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Color::rgb(
+            channel(self.r, other.r),
+            channel(self.g, other.g),
+            channel(self.b, other.b),
+        )
+    }
+}
+
+/// A sequence of equally sized frames, each shown for `delay`.
+#[derive(Debug, Clone)]
+pub struct Animation {
+    pub delay: Duration,
+    frames: Vec<Grid<Color>>,
+}
+
+impl Animation {
+    pub fn new(delay: Duration) -> Self {
+        Animation {
+            delay,
+            frames: Vec::new(),
+        }
+    }
+
+    /// Adds a frame to the end. Panics if it isn't the same size as the frames before it.
+    pub fn push(&mut self, frame: Grid<Color>) {
+        if let Some(first) = self.frames.first() {
+            assert!(
+                (first.width(), first.height()) == (frame.width(), frame.height()),
+                "frames have to be the same size"
+            );
+        }
+        self.frames.push(frame);
+    }
+
+    pub fn frames(&self) -> &[Grid<Color>] {
+        &self.frames
+    }
+}
+
+/// What a day draws: a single image, or an animation of how the solution gets there.
+#[derive(Debug, Clone)]
+pub enum Picture {
+    Still(Grid<Color>),
+    Animation(Animation),
+}
+
+impl Picture {
+    /// The file extension of the format [`Picture::write`] uses: `png` or `gif`.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Picture::Still(_) => "png",
+            Picture::Animation(_) => "gif",
+        }
+    }
+
+    /// Writes a still as a PNG image, or an animation as a GIF, with every cell `scale` pixels
+    /// wide.
+    pub fn write(&self, path: &Path, scale: usize) -> io::Result<()> {
+        let file = io::BufWriter::new(fs::File::create(path)?);
+        match self {
+            Picture::Still(frame) => encode::png(file, frame, scale),
+            Picture::Animation(animation) => encode::gif(file, animation, scale),
+        }
+    }
+
+    /// Writes every frame as a PNG image of its own, `0000.png`, `0001.png` and so on, into
+    /// `dir` (which is created if needed). A still is a single frame. Returns the paths written.
+    pub fn write_frames(&self, dir: &Path, scale: usize) -> io::Result<Vec<PathBuf>> {
+        let frames = match self {
+            Picture::Still(frame) => std::slice::from_ref(frame),
+            Picture::Animation(animation) => animation.frames(),
+        };
+        fs::create_dir_all(dir)?;
+        let mut paths = Vec::new();
+        for (idx, frame) in frames.iter().enumerate() {
+            let path = dir.join(format!("{:04}.png", idx));
+            encode::png(io::BufWriter::new(fs::File::create(&path)?), frame, scale)?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+}
+
+/// A day whose puzzle can be drawn.
+pub trait Render: Solution {
+    /// Draws `input`, usually along with (the steps to) its solution.
+    fn render(input: &Self::Input) -> Picture;
+}
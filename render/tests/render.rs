@@ -0,0 +1,82 @@
+use std::fs::{self, File};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use grid::Grid;
+use render::{Animation, Color, Picture};
+
+const RED: Color = Color::rgb(255, 0, 0);
+
+/// A fresh directory to write pictures into.
+fn out_dir(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-render-{}-{}", std::process::id(), test));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// A 3x2 frame, black but for one red cell at `(x, 1)`.
+fn frame(x: usize) -> Grid<Color> {
+    let mut frame = Grid::new(3, 2, Color::BLACK);
+    frame[(x, 1)] = RED;
+    frame
+}
+
+#[test]
+fn png_is_scaled() {
+    let path = out_dir("png").join("still.png");
+    Picture::Still(frame(2)).write(&path, 4).unwrap();
+
+    let decoder = png::Decoder::new(File::open(&path).unwrap());
+    let mut reader = decoder.read_info().unwrap();
+    let mut data = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut data).unwrap();
+    assert_eq!((info.width, info.height), (12, 8));
+
+    let pixel = |x: usize, y: usize| &data[(y * 12 + x) * 3..][..3];
+    assert_eq!(pixel(8, 4), [255, 0, 0]);
+    assert_eq!(pixel(11, 7), [255, 0, 0]);
+    assert_eq!(pixel(7, 4), [0, 0, 0]);
+    assert_eq!(pixel(8, 3), [0, 0, 0]);
+}
+
+#[test]
+fn gif_has_every_frame() {
+    let mut animation = Animation::new(Duration::from_millis(50));
+    for x in 0..3 {
+        animation.push(frame(x));
+    }
+    let path = out_dir("gif").join("animation.gif");
+    Picture::Animation(animation).write(&path, 2).unwrap();
+
+    let mut options = gif::DecodeOptions::new();
+    options.set_color_output(gif::ColorOutput::RGBA);
+    let mut decoder = options.read_info(File::open(&path).unwrap()).unwrap();
+    let mut red = Vec::new();
+    while let Some(frame) = decoder.read_next_frame().unwrap() {
+        assert_eq!((frame.width, frame.height), (6, 4));
+        assert_eq!(frame.delay, 5);
+        let pixel = (0..24).find(|&idx| frame.buffer[idx * 4..][..3] == [255, 0, 0]);
+        red.push(pixel.map(|idx| (idx % 6, idx / 6)));
+    }
+    assert_eq!(red, [Some((0, 2)), Some((2, 2)), Some((4, 2))]);
+}
+
+#[test]
+fn frames_as_pngs() {
+    let mut animation = Animation::new(Duration::from_millis(100));
+    animation.push(frame(0));
+    animation.push(frame(1));
+    let dir = out_dir("frames").join("day99");
+    let paths = Picture::Animation(animation).write_frames(&dir, 1).unwrap();
+    assert_eq!(paths, [dir.join("0000.png"), dir.join("0001.png")]);
+    assert!(paths.iter().all(|path| path.exists()));
+}
+
+#[test]
+fn mixing_colors() {
+    assert_eq!(Color::BLACK.mix(Color::WHITE, 0.0), Color::BLACK);
+    assert_eq!(Color::BLACK.mix(Color::WHITE, 1.0), Color::WHITE);
+    assert_eq!(Color::BLACK.mix(RED, 0.5), Color::rgb(128, 0, 0));
+    assert_eq!(Color::BLACK.mix(RED, 7.0), RED);
+}
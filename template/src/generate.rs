@@ -0,0 +1,21 @@
+//! Random puzzle inputs: `size` lines of lowercase letters, until this is replaced with a generator
+//! for the real input format.
+
+use common::{Generate, Rng};
+
+use crate::DayXX;
+
+impl Generate for DayXX {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut out = String::new();
+        for _ in 0..size.max(1) {
+            for _ in 0..rng.range(1..=20) {
+                out.push((b'a' + rng.below(26) as u8) as char);
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
@@ -1,21 +1,41 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use common::parse::lines;
+use common::{Answer, ParseError, Solution};
+
+mod generate;
 
 type PuzzleInput = Vec<String>;
 
-pub fn part1(input: &PuzzleInput) -> usize {
+pub fn part1(_input: &PuzzleInput) -> usize {
     1
 }
 
-pub fn part2(input: &PuzzleInput) -> usize {
+pub fn part2(_input: &PuzzleInput) -> usize {
     1
 }
 
-pub fn read_input(filename: &str) -> PuzzleInput {
-    let file = File::open(filename).expect("File not found");
-    let reader = BufReader::new(file);
-    
-    reader.lines()
-        .flatten()
-        .collect()
+pub fn parse(data: &str) -> Result<PuzzleInput, ParseError> {
+    Ok(lines(data).map(|line| line.text.to_string()).collect())
+}
+
+#[cfg(feature = "std")]
+pub fn read_input(filename: &str) -> Result<PuzzleInput, common::InputError> {
+    DayXX::read_input(filename)
+}
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    type Input = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
 }
@@ -0,0 +1,6 @@
+use dayXX::DayXX;
+
+#[test]
+fn examples() {
+    common::fixtures::check::<DayXX>(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+}
@@ -0,0 +1,6 @@
+use dayXX::DayXX;
+
+#[test]
+fn generated() {
+    common::generate::check::<DayXX>();
+}
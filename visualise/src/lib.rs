@@ -0,0 +1,89 @@
+//! A terminal UI that steps through the simulations of the grid puzzles, for watching (and
+//! debugging) how a solution gets to its answer.
+//!
+//! A day implements [`Visualise`] in its `src/visualise.rs`, by wrapping the loop its solution
+//! runs in a [`Simulation`] that advances a step at a time and draws the grid as it is. `aoc
+//! visualise` shows it in a [`Viewer`], which can also be driven without a terminal, for snapshot
+//! tests.
+
+use std::io;
+use std::time::Instant;
+
+use common::Solution;
+use grid::Grid;
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+
+pub use ratatui::crossterm::event::KeyCode;
+pub use render::Color;
+
+mod viewer;
+
+pub use viewer::Viewer;
+
+/// A cell of the grid on screen: a character, in a color, on an optional background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tile {
+    pub symbol: char,
+    pub fg: Color,
+    pub bg: Option<Color>,
+}
+
+impl Tile {
+    pub const fn new(symbol: char, fg: Color) -> Self {
+        Tile {
+            symbol,
+            fg,
+            bg: None,
+        }
+    }
+
+    /// The same tile on a `bg` background.
+    pub const fn on(self, bg: Color) -> Self {
+        Tile {
+            bg: Some(bg),
+            ..self
+        }
+    }
+}
+
+/// A solution's loop, run a step at a time.
+pub trait Simulation {
+    /// Advances the simulation by a step. Returns `false` if it had already finished.
+    fn step(&mut self) -> bool;
+
+    /// The grid as it is now, with a tile per cell.
+    fn frame(&self) -> Grid<Tile>;
+
+    /// A short line on where the simulation is at, shown under the grid.
+    fn status(&self) -> String;
+}
+
+/// A day whose solution can be stepped through.
+pub trait Visualise: Solution {
+    /// Starts the simulation of `input`.
+    fn simulation(input: &Self::Input) -> Box<dyn Simulation + '_>;
+}
+
+/// Shows `simulation` in the terminal until the user quits, with `title` above the grid.
+pub fn run(title: &str, simulation: Box<dyn Simulation + '_>) -> io::Result<()> {
+    let mut viewer = Viewer::new(title, simulation);
+    let mut terminal = ratatui::try_init()?;
+    let result = (|| {
+        let mut last = Instant::now();
+        loop {
+            terminal.draw(|frame| viewer.draw(frame))?;
+            if event::poll(viewer::TICK)? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind == KeyEventKind::Press && !viewer.press(key.code) {
+                        return Ok(());
+                    }
+                }
+            }
+            let now = Instant::now();
+            viewer.tick(now - last);
+            last = now;
+        }
+    })();
+    ratatui::try_restore()?;
+    result
+}
@@ -0,0 +1,217 @@
+//! The UI around a simulation: pausing, stepping, speed and scrolling, and drawing it all.
+
+use std::time::Duration;
+
+use ratatui::backend::TestBackend;
+use ratatui::crossterm::event::KeyCode;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::Block;
+use ratatui::{Frame, Terminal};
+
+use crate::{Color, Simulation};
+
+/// How often the terminal is redrawn, and the simulation advanced.
+pub(crate) const TICK: Duration = Duration::from_millis(50);
+
+/// The speeds, in steps per second, which `+` and `-` double and halve.
+const MIN_SPEED: u32 = 1;
+const MAX_SPEED: u32 = 1 << 16;
+const DEFAULT_SPEED: u32 = 16;
+
+/// How far `H`, `J`, `K` and `L` scroll, as opposed to the arrow keys.
+const PAGE: isize = 10;
+
+const HELP: &str = "space pause  s step  +/- speed  arrows/hjkl scroll  0 top left  q quit";
+
+/// A simulation on screen, along with the state of the controls.
+pub struct Viewer<'a> {
+    title: String,
+    simulation: Box<dyn Simulation + 'a>,
+    steps: usize,
+    finished: bool,
+    paused: bool,
+    /// Steps per second while running
+    speed: u32,
+    /// The part of a step left over from the last tick
+    budget: f64,
+    /// The cell in the top left corner of the screen
+    scroll: (usize, usize),
+}
+
+impl<'a> Viewer<'a> {
+    /// A viewer at the start of `simulation`, running at the default speed.
+    pub fn new(title: &str, simulation: Box<dyn Simulation + 'a>) -> Self {
+        Viewer {
+            title: title.to_string(),
+            simulation,
+            steps: 0,
+            finished: false,
+            paused: false,
+            speed: DEFAULT_SPEED,
+            budget: 0.0,
+            scroll: (0, 0),
+        }
+    }
+
+    /// The number of steps taken so far.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Steps per second while running.
+    pub fn speed(&self) -> u32 {
+        self.speed
+    }
+
+    /// Takes up to `steps` steps, fewer if the simulation finishes first. Returns the number
+    /// taken.
+    pub fn advance(&mut self, steps: usize) -> usize {
+        let mut taken = 0;
+        while taken < steps && !self.finished {
+            match self.simulation.step() {
+                true => taken += 1,
+                false => self.finished = true,
+            }
+        }
+        self.steps += taken;
+        taken
+    }
+
+    /// Lets `elapsed` pass, taking as many steps as the speed allows unless paused.
+    pub fn tick(&mut self, elapsed: Duration) {
+        if self.paused || self.finished {
+            self.budget = 0.0;
+            return;
+        }
+        // Never more than a second's worth at once, so a slow step doesn't snowball
+        let speed = self.speed as f64;
+        self.budget = (self.budget + elapsed.as_secs_f64() * speed).min(speed);
+        let steps = self.budget as usize;
+        self.budget -= steps as f64;
+        self.advance(steps);
+    }
+
+    /// Handles a key press. Returns `false` if it means to quit.
+    pub fn press(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char(' ') => self.paused = !self.paused,
+            KeyCode::Char('s') | KeyCode::Char('.') => {
+                self.paused = true;
+                self.advance(1);
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                self.speed = (self.speed * 2).min(MAX_SPEED);
+            }
+            KeyCode::Char('-') => self.speed = (self.speed / 2).max(MIN_SPEED),
+            KeyCode::Left | KeyCode::Char('h') => self.scroll_by(-1, 0),
+            KeyCode::Right | KeyCode::Char('l') => self.scroll_by(1, 0),
+            KeyCode::Up | KeyCode::Char('k') => self.scroll_by(0, -1),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll_by(0, 1),
+            KeyCode::Char('H') => self.scroll_by(-PAGE, 0),
+            KeyCode::Char('L') => self.scroll_by(PAGE, 0),
+            KeyCode::Char('K') => self.scroll_by(0, -PAGE),
+            KeyCode::Char('J') => self.scroll_by(0, PAGE),
+            KeyCode::Home | KeyCode::Char('0') => self.scroll = (0, 0),
+            _ => {}
+        }
+        true
+    }
+
+    fn scroll_by(&mut self, dx: isize, dy: isize) {
+        let tiles = self.simulation.frame();
+        let scroll = |at: usize, by: isize, len: usize| {
+            at.saturating_add_signed(by).min(len.saturating_sub(1))
+        };
+        self.scroll = (
+            scroll(self.scroll.0, dx, tiles.width()),
+            scroll(self.scroll.1, dy, tiles.height()),
+        );
+    }
+
+    /// Draws the grid in a frame with the title, and the status and the keys below it.
+    pub fn draw(&mut self, frame: &mut Frame) {
+        let [grid_area, status_area, help_area] = Layout::vertical([
+            Constraint::Min(0),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let block = Block::bordered().title(format!(" {} ", self.title));
+        let inner = block.inner(grid_area);
+        frame.render_widget(block, grid_area);
+
+        let tiles = self.simulation.frame();
+        // Don't scroll further than needed to get the bottom right corner on screen
+        let left = self
+            .scroll
+            .0
+            .min(tiles.width().saturating_sub(inner.width as usize));
+        let top = self
+            .scroll
+            .1
+            .min(tiles.height().saturating_sub(inner.height as usize));
+        // Keep it, so scrolling back moves the view straight away
+        self.scroll = (left, top);
+        let width = (inner.width as usize).min(tiles.width() - left);
+        let height = (inner.height as usize).min(tiles.height() - top);
+        let buffer = frame.buffer_mut();
+        for y in 0..height {
+            for x in 0..width {
+                let tile = tiles[(left + x, top + y)];
+                let cell = &mut buffer[(inner.x + x as u16, inner.y + y as u16)];
+                cell.set_char(tile.symbol).set_fg(color(tile.fg));
+                if let Some(bg) = tile.bg {
+                    cell.set_bg(color(bg));
+                }
+            }
+        }
+
+        let state = match (self.finished, self.paused) {
+            (true, _) => "finished".to_string(),
+            (false, true) => "paused".to_string(),
+            (false, false) => format!("{} steps/s", self.speed),
+        };
+        let status = format!(
+            "step {} | {} | {}",
+            self.steps,
+            state,
+            self.simulation.status()
+        );
+        frame.render_widget(Line::from(status), status_area);
+        frame.render_widget(Line::styled(HELP, Style::new().dim()), help_area);
+    }
+
+    /// Draws the viewer on a `width` by `height` screen, and returns the characters on it, a line
+    /// per row without trailing spaces. Needs no terminal, for snapshot tests.
+    pub fn snapshot(&mut self, width: u16, height: u16) -> String {
+        let mut terminal =
+            Terminal::new(TestBackend::new(width, height)).expect("a test backend can't fail");
+        terminal
+            .draw(|frame| self.draw(frame))
+            .expect("a test backend can't fail");
+        let buffer = terminal.backend().buffer();
+        (0..height)
+            .map(|y| {
+                let row: String = (0..width).map(|x| buffer[(x, y)].symbol()).collect();
+                row.trim_end().to_string()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+fn color(color: Color) -> ratatui::style::Color {
+    ratatui::style::Color::Rgb(color.r, color.g, color.b)
+}
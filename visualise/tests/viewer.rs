@@ -0,0 +1,149 @@
+use std::time::Duration;
+
+use grid::Grid;
+use visualise::{Color, KeyCode, Simulation, Tile, Viewer};
+
+/// Fills a `width` by `height` grid with `#`, a cell per step, row by row.
+struct Fill {
+    width: usize,
+    height: usize,
+    filled: usize,
+}
+
+impl Simulation for Fill {
+    fn step(&mut self) -> bool {
+        if self.filled == self.width * self.height {
+            return false;
+        }
+        self.filled += 1;
+        true
+    }
+
+    fn frame(&self) -> Grid<Tile> {
+        let mut grid = Grid::new(self.width, self.height, Tile::new('.', Color::WHITE));
+        for idx in 0..self.filled {
+            grid[(idx % self.width, idx / self.width)] = Tile::new('#', Color::WHITE);
+        }
+        grid
+    }
+
+    fn status(&self) -> String {
+        format!("{} filled", self.filled)
+    }
+}
+
+fn viewer(width: usize, height: usize) -> Viewer<'static> {
+    Viewer::new(
+        "Fill",
+        Box::new(Fill {
+            width,
+            height,
+            filled: 0,
+        }),
+    )
+}
+
+#[test]
+fn snapshot() {
+    let mut viewer = viewer(4, 2);
+    viewer.advance(3);
+    let expected = [
+        "┌ Fill ────────────────────────┐",
+        "│###.                          │",
+        "│....                          │",
+        "└──────────────────────────────┘",
+        "step 3 | 16 steps/s | 3 filled",
+        "space pause  s step  +/- speed",
+    ];
+    assert_eq!(viewer.snapshot(32, 6), expected.join("\n"));
+}
+
+#[test]
+fn stops_when_finished() {
+    let mut viewer = viewer(2, 2);
+    assert_eq!(viewer.advance(10), 4);
+    assert!(viewer.is_finished());
+    assert_eq!(viewer.steps(), 4);
+    assert!(viewer
+        .snapshot(40, 6)
+        .contains("step 4 | finished | 4 filled"));
+}
+
+#[test]
+fn runs_at_its_speed() {
+    let mut viewer = viewer(10, 10);
+    viewer.tick(Duration::from_millis(500));
+    assert_eq!(viewer.steps(), 8);
+
+    viewer.press(KeyCode::Char('+'));
+    assert_eq!(viewer.speed(), 32);
+    viewer.tick(Duration::from_millis(250));
+    assert_eq!(viewer.steps(), 16);
+
+    // Ticks that take long only catch up on a second's worth
+    viewer.tick(Duration::from_secs(60));
+    assert_eq!(viewer.steps(), 48);
+}
+
+#[test]
+fn pause_and_step() {
+    let mut viewer = viewer(10, 10);
+    viewer.press(KeyCode::Char(' '));
+    assert!(viewer.is_paused());
+    viewer.tick(Duration::from_secs(1));
+    assert_eq!(viewer.steps(), 0);
+
+    viewer.press(KeyCode::Char('s'));
+    viewer.press(KeyCode::Char('.'));
+    assert_eq!(viewer.steps(), 2);
+    assert!(viewer
+        .snapshot(40, 15)
+        .contains("step 2 | paused | 2 filled"));
+
+    viewer.press(KeyCode::Char(' '));
+    viewer.tick(Duration::from_secs(1));
+    assert_eq!(viewer.steps(), 18);
+}
+
+#[test]
+fn scrolls_up_to_the_corner() {
+    let mut viewer = viewer(30, 3);
+    viewer.advance(31);
+    // 10 columns on screen, so scrolling stops at column 20
+    for _ in 0..5 {
+        viewer.press(KeyCode::Char('L'));
+    }
+    let snapshot = viewer.snapshot(12, 7);
+    let rows: Vec<&str> = snapshot.lines().collect();
+    assert_eq!(rows[1], "│##########│");
+    assert_eq!(rows[2], "│..........│");
+
+    viewer.press(KeyCode::Char('0'));
+    let snapshot = viewer.snapshot(12, 7);
+    let rows: Vec<&str> = snapshot.lines().collect();
+    assert_eq!(rows[2], "│#.........│");
+}
+
+#[test]
+fn scrolls_back_from_the_corner() {
+    let mut viewer = viewer(30, 3);
+    viewer.advance(51);
+    for _ in 0..5 {
+        viewer.press(KeyCode::Char('L'));
+    }
+    let snapshot = viewer.snapshot(12, 7);
+    assert_eq!(snapshot.lines().nth(2), Some("│#.........│"));
+
+    // Already at column 20 on screen, so one step back shows column 19
+    viewer.press(KeyCode::Char('h'));
+    let snapshot = viewer.snapshot(12, 7);
+    assert_eq!(snapshot.lines().nth(2), Some("│##........│"));
+}
+
+#[test]
+fn quits() {
+    let mut viewer = viewer(2, 2);
+    assert!(viewer.press(KeyCode::Right));
+    assert!(!viewer.press(KeyCode::Char('q')));
+    assert!(!viewer.press(KeyCode::Esc));
+}
@@ -0,0 +1,25 @@
+// Loads the solvers built for wasm32-unknown-unknown, in a browser or in Node:
+//
+//   const aoc = await load(await fetch("aoc_wasm.wasm").then((response) => response.arrayBuffer()));
+//   aoc.solve(17, input); // {day: 17, part1: "102", part2: "94"} or {day: 17, error: "..."}
+//
+// The module needs no imports, so any other wasm runtime can call the same three functions.
+
+export async function load(bytes) {
+  const { instance } = await WebAssembly.instantiate(bytes, {});
+  const { memory, aoc_alloc, aoc_solve, aoc_output } = instance.exports;
+  const encoder = new TextEncoder();
+  const decoder = new TextDecoder();
+
+  return {
+    solve(day, input) {
+      const data = encoder.encode(input);
+      const ptr = aoc_alloc(data.length);
+      // The memory can grow during any call, so views of it are only valid until the next one
+      new Uint8Array(memory.buffer, ptr, data.length).set(data);
+      const len = aoc_solve(day, ptr, data.length);
+      const output = new Uint8Array(memory.buffer, aoc_output(), len);
+      return JSON.parse(decoder.decode(output));
+    },
+  };
+}
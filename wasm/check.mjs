@@ -0,0 +1,65 @@
+// Checks the wasm build of the solvers against the recorded answers of every day: the worked
+// examples in dayNN/fixtures/answers.txt, and the puzzle inputs in dayNN/answers.txt if present.
+//
+//   node wasm/check.mjs target/wasm32-unknown-unknown/release/aoc_wasm.wasm
+//
+// Usually run through `cargo xtask wasm`.
+
+import { existsSync, readdirSync, readFileSync } from "node:fs";
+import { join } from "node:path";
+import { load } from "./aoc.mjs";
+
+const root = new URL("..", import.meta.url).pathname;
+const module = readFileSync(process.argv[2]);
+let aoc = await load(module);
+
+// The same format as common::answers: an input file and the answers to both parts, `?` if unknown
+function recorded(path) {
+  if (!existsSync(path)) {
+    return [];
+  }
+  return readFileSync(path, "utf8")
+    .split("\n")
+    .map((line) => line.trim())
+    .filter((line) => line && !line.startsWith("#"))
+    .map((line) => line.split(/\s+/));
+}
+
+let checked = 0;
+const failures = [];
+const days = readdirSync(root).filter((name) => /^day\d\d$/.test(name)).sort();
+for (const name of days) {
+  const day = Number(name.slice(3));
+  for (const dir of [join(root, name, "fixtures"), join(root, name)]) {
+    for (const [input, ...expected] of recorded(join(dir, "answers.txt"))) {
+      const path = join(dir, input);
+      if (!existsSync(path)) {
+        continue;
+      }
+      let output;
+      try {
+        output = aoc.solve(day, readFileSync(path, "utf8"));
+      } catch (err) {
+        // A panic aborts, which leaves the instance unusable, so start over with a fresh one
+        output = { error: `${err}` };
+        aoc = await load(module);
+      }
+      for (const [idx, answer] of expected.entries()) {
+        if (answer === "?") {
+          continue;
+        }
+        checked += 1;
+        const actual = output.error ?? output[`part${idx + 1}`];
+        if (actual !== answer) {
+          failures.push(`${name}/${input} part ${idx + 1}: expected ${answer}, got ${actual}`);
+        }
+      }
+    }
+  }
+}
+
+for (const failure of failures) {
+  console.log(failure);
+}
+console.log(`${checked} answers checked, ${failures.length} failures`);
+process.exit(failures.length === 0 ? 0 : 1);
@@ -0,0 +1,46 @@
+//! The functions the module exports, for hosts that can only pass numbers to it.
+//!
+//! The host asks for a buffer with [`aoc_alloc`], copies the UTF-8 input into it and passes it to
+//! [`aoc_solve`], which returns the length of the JSON [`Output`]. That is then in memory at
+//! [`aoc_output`] until the next call to `aoc_solve`.
+
+use std::sync::Mutex;
+
+use crate::{run, Output};
+
+/// The JSON of the latest output, kept here for the host to read.
+static OUTPUT: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+
+/// Allocates a buffer of `len` bytes for the input of [`aoc_solve`].
+#[no_mangle]
+pub extern "C" fn aoc_alloc(len: usize) -> *mut u8 {
+    Box::into_raw(vec![0u8; len].into_boxed_slice()).cast()
+}
+
+/// Solves both parts of `day` for the input in the buffer at `ptr`, and frees the buffer. Returns
+/// the length of the output, which [`aoc_output`] points to.
+///
+/// # Safety
+///
+/// `ptr` has to come from `aoc_alloc(len)`, and can't be used again afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(day: u32, ptr: *mut u8, len: usize) -> usize {
+    let input = Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr, len));
+    let output = match std::str::from_utf8(&input) {
+        Ok(input) => run(day, input),
+        Err(_) => Output::Failed {
+            day,
+            error: "the input is not valid UTF-8".to_string(),
+        },
+    };
+    let json = output.to_json().into_bytes();
+    let len = json.len();
+    *OUTPUT.lock().unwrap() = json;
+    len
+}
+
+/// Where the output of the latest [`aoc_solve`] is.
+#[no_mangle]
+pub extern "C" fn aoc_output() -> *const u8 {
+    OUTPUT.lock().unwrap().as_ptr()
+}
@@ -0,0 +1,98 @@
+//! The solvers built for WebAssembly: given a day and its puzzle input as text, returns the
+//! answers, without touching a filesystem.
+//!
+//! `cargo build --release -p aoc-wasm --lib --target wasm32-unknown-unknown` builds a module with
+//! the functions in [`abi`], for a browser or any other wasm runtime (`aoc.mjs` loads it in
+//! JavaScript). `--target wasm32-wasip1` builds a command instead, which reads the input from stdin
+//! and prints the answers as JSON, for WASI runtimes such as wasmtime.
+
+use common::{ParseError, Solution};
+use serde::Serialize;
+
+pub mod abi;
+
+/// Generates the list of the days that are built in, and a `solve` function that parses the input
+/// and solves both parts of the day. The same table as the `aoc` runner's, without the parts that
+/// need an operating system.
+macro_rules! days {
+    ($($day:literal => $solution:path),* $(,)?) => {
+        /// All days that are built in, in ascending order.
+        pub const DAYS: &[u32] = &[$($day),*];
+
+        /// Parses the puzzle input `input` of `day`, and solves both parts.
+        ///
+        /// Returns `None` if `day` is not built in.
+        fn solve(day: u32, input: &str) -> Option<Result<[String; 2], ParseError>> {
+            match day {
+                $($day => Some(<$solution as Solution>::parse(input).map(|input| {
+                    [
+                        <$solution as Solution>::part1(&input).to_string(),
+                        <$solution as Solution>::part2(&input).to_string(),
+                    ]
+                })),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+days! {
+    1 => day01::Day01,
+    2 => day02::Day02,
+    3 => day03::Day03,
+    4 => day04::Day04,
+    5 => day05::Day05,
+    6 => day06::Day06,
+    7 => day07::Day07,
+    8 => day08::Day08,
+    9 => day09::Day09,
+    10 => day10::Day10,
+    11 => day11::Day11,
+    12 => day12::Day12,
+    13 => day13::Day13,
+    14 => day14::Day14,
+    15 => day15::Day15,
+    16 => day16::Day16,
+    17 => day17::Day17,
+    18 => day18::Day18,
+    19 => day19::Day19,
+}
+
+/// The answers to a day, or why there are none.
+///
+/// As JSON, `{"day":17,"part1":"102","part2":"94"}` or `{"day":17,"error":"..."}`. The answers are
+/// strings rather than numbers, as some are too large for a JavaScript number to hold exactly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Output {
+    Solved {
+        day: u32,
+        part1: String,
+        part2: String,
+    },
+    Failed {
+        day: u32,
+        error: String,
+    },
+}
+
+impl Output {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("outputs serialize")
+    }
+}
+
+/// Solves both parts of `day` for the puzzle input `input`.
+pub fn run(day: u32, input: &str) -> Output {
+    match solve(day, input) {
+        Some(Ok([part1, part2])) => Output::Solved { day, part1, part2 },
+        Some(Err(err)) => Output::Failed {
+            day,
+            error: err.to_string(),
+        },
+        None => Output::Failed {
+            day,
+            error: format!("day {} is not available", day),
+        },
+    }
+}
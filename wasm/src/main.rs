@@ -0,0 +1,25 @@
+use std::io::{self, Read};
+use std::process::ExitCode;
+
+use aoc_wasm::Output;
+
+// Usage: aoc-wasm DAY < INPUT, e.g. under wasmtime:
+//   wasmtime target/wasm32-wasip1/release/aoc-wasm.wasm 17 < day17/input.txt
+fn main() -> ExitCode {
+    let Some(day) = std::env::args().nth(1).and_then(|day| day.parse().ok()) else {
+        eprintln!("usage: aoc-wasm DAY < INPUT");
+        return ExitCode::FAILURE;
+    };
+    let mut input = String::new();
+    if let Err(err) = io::stdin().read_to_string(&mut input) {
+        eprintln!("failed to read input: {}", err);
+        return ExitCode::FAILURE;
+    }
+
+    let output = aoc_wasm::run(day, &input);
+    println!("{}", output.to_json());
+    match output {
+        Output::Solved { .. } => ExitCode::SUCCESS,
+        Output::Failed { .. } => ExitCode::FAILURE,
+    }
+}
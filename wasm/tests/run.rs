@@ -0,0 +1,74 @@
+use aoc_wasm::abi::{aoc_alloc, aoc_output, aoc_solve};
+use aoc_wasm::{run, Output, DAYS};
+
+#[test]
+fn examples() {
+    for &day in DAYS {
+        let dir = format!("{}/../day{:02}/fixtures", env!("CARGO_MANIFEST_DIR"), day);
+        let text = std::fs::read_to_string(format!("{}/answers.txt", dir)).unwrap();
+        for recorded in common::answers::parse(&text).unwrap() {
+            let input = std::fs::read_to_string(format!("{}/{}", dir, recorded.input)).unwrap();
+            let Output::Solved { part1, part2, .. } = run(day, &input) else {
+                panic!("day {} failed on {}", day, recorded.input);
+            };
+            for (part, answer) in [(1, part1), (2, part2)] {
+                if let Some(expected) = recorded.expected(part) {
+                    assert_eq!(
+                        answer, expected,
+                        "day {} part {} of {}",
+                        day, part, recorded.input
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn outputs_as_json() {
+    let input = include_str!("../../day17/fixtures/example1.txt");
+    assert_eq!(
+        run(17, input).to_json(),
+        r#"{"day":17,"part1":"102","part2":"94"}"#
+    );
+    assert_eq!(
+        run(25, input).to_json(),
+        r#"{"day":25,"error":"day 25 is not available"}"#
+    );
+}
+
+#[test]
+fn reports_parse_errors() {
+    let Output::Failed { day, error } = run(17, "12\n3x\n") else {
+        panic!("expected a parse error");
+    };
+    assert_eq!(day, 17);
+    assert!(!error.is_empty());
+}
+
+#[test]
+fn solves_through_the_exported_functions() {
+    let input = include_str!("../../day17/fixtures/example1.txt").as_bytes();
+    let output = unsafe {
+        let ptr = aoc_alloc(input.len());
+        std::ptr::copy_nonoverlapping(input.as_ptr(), ptr, input.len());
+        let len = aoc_solve(17, ptr, input.len());
+        std::slice::from_raw_parts(aoc_output(), len).to_vec()
+    };
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        r#"{"day":17,"part1":"102","part2":"94"}"#
+    );
+
+    let invalid = [0xff, 0xfe];
+    let output = unsafe {
+        let ptr = aoc_alloc(invalid.len());
+        std::ptr::copy_nonoverlapping(invalid.as_ptr(), ptr, invalid.len());
+        let len = aoc_solve(1, ptr, invalid.len());
+        std::slice::from_raw_parts(aoc_output(), len).to_vec()
+    };
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        r#"{"day":1,"error":"the input is not valid UTF-8"}"#
+    );
+}
@@ -0,0 +1,212 @@
+//! Runs every day's `benches/bench.rs` and summarises criterion's saved estimates.
+//!
+//! Each bench file puts its benchmarks in a group named after the day, so criterion stores the
+//! results for e.g. day 17's part 1 in `target/criterion/day17/part1/<baseline>/estimates.json`.
+
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::Duration;
+
+use clap::ValueEnum;
+use common::runner::format_duration;
+use serde::Deserialize;
+
+use crate::{days, workspace_root};
+
+/// The benchmarks that make up a row of the table.
+const BENCHES: [&str; 3] = ["parse", "part1", "part2"];
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Days to include, e.g. `3 17` (defaults to every day)
+    days: Vec<u8>,
+
+    /// Don't run the benches, only summarise the results of the last run
+    #[arg(long)]
+    no_run: bool,
+
+    /// Save the results as a named baseline, to compare against later
+    #[arg(long, value_name = "NAME")]
+    save_baseline: Option<String>,
+
+    /// Compare the results against a baseline saved earlier with `--save-baseline`
+    #[arg(long, value_name = "NAME")]
+    baseline: Option<String>,
+
+    /// Output format of the table
+    #[arg(long, value_enum, default_value_t = Format::Markdown)]
+    format: Format,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Markdown,
+    Csv,
+}
+
+#[derive(Deserialize)]
+struct Estimates {
+    median: Estimate,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+    /// In nanoseconds.
+    point_estimate: f64,
+}
+
+/// Median timings in nanoseconds, per benchmark in [`BENCHES`].
+type Row = [Option<f64>; 3];
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let available = days()?;
+    let days = match args.days.is_empty() {
+        true => available,
+        false => {
+            if let Some(day) = args.days.iter().find(|day| !available.contains(day)) {
+                return Err(format!("day {} does not exist", day));
+            }
+            args.days.clone()
+        }
+    };
+
+    if !args.no_run {
+        run_benches(&days, args.save_baseline.as_deref())?;
+    }
+
+    // Criterion always writes the latest run to `new`, and also to the named baseline if asked
+    let current = args.save_baseline.as_deref().unwrap_or("new");
+    let rows = days
+        .iter()
+        .map(|&day| {
+            let row = read_row(day, current)?;
+            let baseline = match &args.baseline {
+                Some(name) => Some(read_row(day, name)?),
+                None => None,
+            };
+            Ok((day, row, baseline))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    if rows
+        .iter()
+        .all(|(_, row, _)| row.iter().all(Option::is_none))
+    {
+        return Err("no benchmark results found, run without --no-run first".to_string());
+    }
+    if let Some(name) = &args.baseline {
+        if rows
+            .iter()
+            .all(|(_, _, baseline)| baseline.iter().flatten().all(Option::is_none))
+        {
+            return Err(format!("no results found for baseline '{}'", name));
+        }
+    }
+
+    match args.format {
+        Format::Markdown => print_markdown(&rows),
+        Format::Csv => print_csv(&rows, args.baseline.is_some()),
+    }
+    Ok(())
+}
+
+fn run_benches(days: &[u8], save_baseline: Option<&str>) -> Result<(), String> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut command = Command::new(cargo);
+    command
+        .current_dir(workspace_root())
+        .args(["bench", "--bench", "bench"]);
+    for day in days {
+        command.arg("-p").arg(format!("day{:02}", day));
+    }
+    if let Some(name) = save_baseline {
+        command.args(["--", "--save-baseline", name]);
+    }
+
+    let status = command
+        .status()
+        .map_err(|err| format!("failed to run cargo bench: {}", err))?;
+    match status.success() {
+        true => Ok(()),
+        false => Err(format!("cargo bench failed ({})", status)),
+    }
+}
+
+fn criterion_dir() -> PathBuf {
+    let target = std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| workspace_root().join("target"));
+    target.join("criterion")
+}
+
+/// Reads the medians of `day`'s benchmarks from the results saved under `baseline`. Benchmarks
+/// that have no results are left empty.
+fn read_row(day: u8, baseline: &str) -> Result<Row, String> {
+    let mut row = [None; 3];
+    for (cell, bench) in row.iter_mut().zip(BENCHES) {
+        let path = criterion_dir()
+            .join(format!("day{:02}", day))
+            .join(bench)
+            .join(baseline)
+            .join("estimates.json");
+        let Ok(text) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let estimates: Estimates =
+            serde_json::from_str(&text).map_err(|err| format!("{}: {}", path.display(), err))?;
+        *cell = Some(estimates.median.point_estimate);
+    }
+    Ok(row)
+}
+
+/// Formats one of criterion's estimates, in nanoseconds, the way `aoc run` prints its timings.
+fn format_ns(ns: f64) -> String {
+    format_duration(Duration::from_secs_f64(ns / 1e9))
+}
+
+/// The relative change from `before` to `after`, in percent.
+fn change(after: f64, before: f64) -> f64 {
+    (after - before) / before * 100.0
+}
+
+fn print_markdown(rows: &[(u8, Row, Option<Row>)]) {
+    println!("| Day | Parse | Part 1 | Part 2 |");
+    println!("|----:|------:|-------:|-------:|");
+    for (day, row, baseline) in rows {
+        let cells: Vec<String> = (0..BENCHES.len())
+            .map(|idx| match (row[idx], baseline.map(|b| b[idx])) {
+                (None, _) => "-".to_string(),
+                (Some(ns), Some(Some(before))) => {
+                    format!("{} ({:+.1}%)", format_ns(ns), change(ns, before))
+                }
+                (Some(ns), _) => format_ns(ns),
+            })
+            .collect();
+        println!("| {:02} | {} |", day, cells.join(" | "));
+    }
+}
+
+fn print_csv(rows: &[(u8, Row, Option<Row>)], with_baseline: bool) {
+    let mut header = vec!["day".to_string()];
+    header.extend(BENCHES.iter().map(|bench| format!("{}_ns", bench)));
+    if with_baseline {
+        header.extend(BENCHES.iter().map(|bench| format!("{}_baseline_ns", bench)));
+        header.extend(BENCHES.iter().map(|bench| format!("{}_change_pct", bench)));
+    }
+    println!("{}", header.join(","));
+
+    let cell = |value: Option<f64>| value.map(|v| format!("{:.2}", v)).unwrap_or_default();
+    for (day, row, baseline) in rows {
+        let mut cells = vec![day.to_string()];
+        cells.extend(row.iter().map(|&ns| cell(ns)));
+        if let Some(baseline) = baseline {
+            cells.extend(baseline.iter().map(|&ns| cell(ns)));
+            cells.extend(
+                row.iter()
+                    .zip(baseline)
+                    .map(|(&after, &before)| cell(after.zip(before).map(|(a, b)| change(a, b)))),
+            );
+        }
+        println!("{}", cells.join(","));
+    }
+}
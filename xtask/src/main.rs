@@ -0,0 +1,73 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+
+mod bench;
+mod new_day;
+mod wasm;
+mod watch;
+
+#[derive(Parser)]
+#[command(
+    name = "xtask",
+    about = "Development tasks for the Advent of Code 2023 workspace"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the criterion benches and print a table of the median timings
+    Bench(bench::Args),
+    /// Create a new day from the template, and register it with the workspace and the runner
+    NewDay(new_day::Args),
+    /// Build the solvers for WebAssembly and check them against the recorded answers in Node
+    Wasm(wasm::Args),
+    /// Rebuild and rerun a day, and its example tests, whenever its code or input changes
+    Watch(watch::Args),
+}
+
+/// Root of the workspace.
+fn workspace_root() -> PathBuf {
+    PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+}
+
+/// All `dayNN` crates in the workspace, in ascending order.
+fn days() -> Result<Vec<u8>, String> {
+    let root = workspace_root();
+    let entries = std::fs::read_dir(&root).map_err(|err| format!("{}: {}", root.display(), err))?;
+
+    let mut days: Vec<u8> = entries
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name();
+            let day = name.to_str()?.strip_prefix("day")?;
+            match day.len() == 2 {
+                true => day.parse().ok(),
+                false => None,
+            }
+        })
+        .collect();
+    days.sort_unstable();
+    Ok(days)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Bench(args) => bench::run(&args),
+        Command::NewDay(args) => new_day::run(&args),
+        Command::Wasm(args) => wasm::run(&args),
+        Command::Watch(args) => watch::run(&args),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
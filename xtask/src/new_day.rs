@@ -0,0 +1,230 @@
+//! Scaffolds a new `dayNN` crate from `template/` and registers it with the workspace, the `aoc`
+//! runner and the fuzz targets.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::workspace_root;
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// The day to create, e.g. `20` or `day20`
+    day: String,
+}
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let day: u8 = args
+        .day
+        .strip_prefix("day")
+        .unwrap_or(&args.day)
+        .parse()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| format!("'{}' is not a day between 1 and 25", args.day))?;
+
+    let root = workspace_root();
+    let name = format!("day{:02}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", name));
+    }
+
+    let fuzz_target = root.join(format!("fuzz/fuzz_targets/{}.rs", name));
+    if fuzz_target.exists() {
+        return Err(format!("{} already exists", fuzz_target.display()));
+    }
+
+    // Check that every file we're about to touch can be updated before creating anything
+    let registrations = [
+        (
+            "Cargo.toml",
+            format!("    \"{}\",", name),
+            workspace_member as fn(&str) -> Option<u8>,
+            insert_entry as Insert,
+        ),
+        (
+            "aoc/Cargo.toml",
+            format!("{} = {{ path = \"../{}\" }}", name, name),
+            runner_dependency,
+            insert_entry,
+        ),
+        (
+            "aoc/src/days.rs",
+            format!("    {} => {}::Day{:02},", day, name, day),
+            runner_dispatch,
+            insert_entry,
+        ),
+        (
+            "fuzz/Cargo.toml",
+            format!("{} = {{ path = \"../{}\" }}", name, name),
+            runner_dependency,
+            insert_entry,
+        ),
+        (
+            "fuzz/Cargo.toml",
+            FUZZ_BIN.replace("dayXX", &name),
+            fuzz_bin,
+            insert_block,
+        ),
+        (
+            "wasm/Cargo.toml",
+            format!(
+                "{} = {{ path = \"../{}\", default-features = false }}",
+                name, name
+            ),
+            runner_dependency,
+            insert_entry,
+        ),
+        (
+            "wasm/src/lib.rs",
+            format!("    {} => {}::Day{:02},", day, name, day),
+            runner_dispatch,
+            insert_entry,
+        ),
+    ];
+    let mut updated: Vec<(PathBuf, String)> = Vec::new();
+    for (file, line, entry, insert) in &registrations {
+        let path = root.join(file);
+        // A file with more than one registration gets them all, one after the other
+        let text = match updated.iter().position(|(other, _)| *other == path) {
+            Some(idx) => updated.remove(idx).1,
+            None => fs::read_to_string(&path).map_err(|err| format!("{}: {}", file, err))?,
+        };
+        let text = insert(&text, day, line, *entry)
+            .ok_or_else(|| format!("{}: could not find where to register {}", file, name))?;
+        updated.push((path, text));
+    }
+
+    copy_template(&root.join("template"), &dir, day)?;
+    for (path, text) in updated {
+        fs::write(&path, text).map_err(|err| format!("{}: {}", path.display(), err))?;
+    }
+    let target = FUZZ_TARGET
+        .replace("dayXX", &name)
+        .replace("DayXX", &format!("Day{:02}", day));
+    fs::write(&fuzz_target, target).map_err(|err| format!("{}: {}", fuzz_target.display(), err))?;
+
+    println!("Created {}", name);
+    println!("  - put the puzzle input in {}/input.txt", name);
+    println!(
+        "  - put the examples in {}/fixtures/ and their answers in fixtures/answers.txt",
+        name
+    );
+    Ok(())
+}
+
+/// Recursively copies `from` to `to`, replacing the `dayXX`/`DayXX` placeholders in every file.
+fn copy_template(from: &Path, to: &Path, day: u8) -> Result<(), String> {
+    fs::create_dir(to).map_err(|err| format!("{}: {}", to.display(), err))?;
+    let entries = fs::read_dir(from).map_err(|err| format!("{}: {}", from.display(), err))?;
+    for entry in entries {
+        let entry = entry.map_err(|err| format!("{}: {}", from.display(), err))?;
+        let name = entry.file_name();
+        // Build artifacts from compiling the template on its own
+        if name == "target" || name == "Cargo.lock" {
+            continue;
+        }
+
+        let (src, dst) = (entry.path(), to.join(&name));
+        match src.is_dir() {
+            true => copy_template(&src, &dst, day)?,
+            false => {
+                let text = fs::read_to_string(&src)
+                    .map_err(|err| format!("{}: {}", src.display(), err))?;
+                let text = text
+                    .replace("dayXX", &format!("day{:02}", day))
+                    .replace("DayXX", &format!("Day{:02}", day));
+                fs::write(&dst, text).map_err(|err| format!("{}: {}", dst.display(), err))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The fuzz target of a day, in `fuzz/fuzz_targets/dayNN.rs`.
+const FUZZ_TARGET: &str = "#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| common::fuzz::run::<dayXX::DayXX>(data));
+";
+
+/// The entry for a day's fuzz target in `fuzz/Cargo.toml`.
+const FUZZ_BIN: &str = "[[bin]]
+name = \"dayXX\"
+path = \"fuzz_targets/dayXX.rs\"
+test = false
+doc = false
+bench = false";
+
+/// Adds an entry for `day` to a file, see [`insert_entry`].
+type Insert = fn(&str, u8, &str, fn(&str) -> Option<u8>) -> Option<String>;
+
+/// Inserts `line` among the existing per-day entries of `text`, keeping them sorted by day.
+/// `entry` recognises those lines, returning the day they belong to. Returns `None` if there are
+/// no entries to insert next to.
+fn insert_entry(text: &str, day: u8, line: &str, entry: fn(&str) -> Option<u8>) -> Option<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let entries: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, line)| Some((idx, entry(line)?)))
+        .collect();
+
+    let idx = match entries.iter().find(|(_, other)| *other > day) {
+        Some((idx, _)) => *idx,
+        None => entries.last()?.0 + 1,
+    };
+    lines.insert(idx, line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Like [`insert_entry`], for entries of more than one line: `block` becomes a paragraph of its
+/// own, among the paragraphs that have a line that `entry` recognises.
+fn insert_block(text: &str, day: u8, block: &str, entry: fn(&str) -> Option<u8>) -> Option<String> {
+    let mut blocks: Vec<&str> = text.trim_end().split("\n\n").collect();
+    let entries: Vec<(usize, u8)> = blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, block)| Some((idx, block.lines().find_map(entry)?)))
+        .collect();
+
+    let idx = match entries.iter().find(|(_, other)| *other > day) {
+        Some((idx, _)) => *idx,
+        None => entries.last()?.0 + 1,
+    };
+    blocks.insert(idx, block);
+    Some(blocks.join("\n\n") + "\n")
+}
+
+/// Parses the `NN` out of a string starting with `dayNN`.
+fn day_prefix(s: &str) -> Option<u8> {
+    let digits = s.strip_prefix("day")?.get(..2)?;
+    match digits.bytes().all(|b| b.is_ascii_digit()) {
+        true => digits.parse().ok(),
+        false => None,
+    }
+}
+
+/// `    "day17",` in the workspace members.
+fn workspace_member(line: &str) -> Option<u8> {
+    let member = line.trim().strip_prefix('"')?.strip_suffix("\",")?;
+    day_prefix(member).filter(|_| member.len() == 5)
+}
+
+/// `day17 = { path = "../day17" }` in the runner's dependencies.
+fn runner_dependency(line: &str) -> Option<u8> {
+    day_prefix(line).filter(|_| line[5..].starts_with(" = "))
+}
+
+/// `    17 => day17::Day17,` in the runner's dispatch table.
+fn runner_dispatch(line: &str) -> Option<u8> {
+    let (_, path) = line.trim().split_once(" => ")?;
+    day_prefix(path)
+}
+
+/// `name = "day17"` in a fuzz target's `[[bin]]` section.
+fn fuzz_bin(line: &str) -> Option<u8> {
+    let name = line.strip_prefix("name = \"")?.strip_suffix('"')?;
+    day_prefix(name).filter(|_| name.len() == 5)
+}
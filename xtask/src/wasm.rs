@@ -0,0 +1,70 @@
+//! Builds the solvers for WebAssembly and checks them against the recorded answers in Node.
+//!
+//! The module is built with overflow checks, so that an answer that doesn't fit in a 32-bit `usize`
+//! fails the check instead of silently wrapping around.
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use crate::workspace_root;
+
+const TARGET: &str = "wasm32-unknown-unknown";
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Don't build the module, only check the one from the last build
+    #[arg(long)]
+    no_build: bool,
+}
+
+pub fn run(args: &Args) -> Result<(), String> {
+    if !args.no_build {
+        build()?;
+    }
+
+    let module = target_dir()
+        .join(TARGET)
+        .join("release")
+        .join("aoc_wasm.wasm");
+    if !module.exists() {
+        return Err(format!("{} does not exist", module.display()));
+    }
+    let status = Command::new("node")
+        .current_dir(workspace_root())
+        .arg("wasm/check.mjs")
+        .arg(&module)
+        .status()
+        .map_err(|err| format!("failed to run node: {}", err))?;
+    match status.success() {
+        true => Ok(()),
+        false => Err(format!("the wasm check failed ({})", status)),
+    }
+}
+
+fn build() -> Result<(), String> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let status = Command::new(cargo)
+        .current_dir(workspace_root())
+        .env("CARGO_PROFILE_RELEASE_OVERFLOW_CHECKS", "true")
+        .args([
+            "build",
+            "--release",
+            "-p",
+            "aoc-wasm",
+            "--lib",
+            "--target",
+            TARGET,
+        ])
+        .status()
+        .map_err(|err| format!("failed to run cargo build: {}", err))?;
+    match status.success() {
+        true => Ok(()),
+        false => Err(format!("cargo build failed ({})", status)),
+    }
+}
+
+fn target_dir() -> PathBuf {
+    std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| workspace_root().join("target"))
+}
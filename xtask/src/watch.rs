@@ -0,0 +1,242 @@
+//! Re-runs a day whenever its code or input changes, showing how the answers changed.
+//!
+//! Changes are picked up through the operating system's file notifications (inotify on Linux), on
+//! the day's directory and the input file. Every batch of changes rebuilds and runs the day's own
+//! binary, which only rebuilds what the day depends on, and then its `examples` test.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use notify::{EventKind, RecursiveMode, Watcher};
+use serde::Deserialize;
+
+use crate::{days, workspace_root};
+
+/// How long to wait for things to settle after a change, as editors and `git checkout` often
+/// write several files (or the same file more than once) in a row.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Day to watch, e.g. `17`
+    day: u8,
+
+    /// Input file to run instead of the day's `input.txt`
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Don't run the example tests after the answers
+    #[arg(long)]
+    no_tests: bool,
+}
+
+/// A line of the per-day binary's `--format json` output, as far as needed here.
+#[derive(Deserialize)]
+struct Record {
+    part: u8,
+    answer: serde_json::Value,
+}
+
+/// The answers to both parts, if the day ran at all.
+type Answers = [Option<String>; 2];
+
+pub fn run(args: &Args) -> Result<(), String> {
+    if !days()?.contains(&args.day) {
+        return Err(format!("day {} does not exist", args.day));
+    }
+    let name = format!("day{:02}", args.day);
+    let dir = workspace_root()
+        .join(&name)
+        .canonicalize()
+        .map_err(|err| format!("{}: {}", name, err))?;
+    let input = match &args.input {
+        Some(input) => input.canonicalize(),
+        None => dir.join("input.txt").canonicalize(),
+    }
+    .map_err(|err| format!("input: {}", err))?;
+
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)
+        .map_err(|err| format!("failed to watch for changes: {}", err))?;
+    watcher
+        .watch(&dir, RecursiveMode::Recursive)
+        .map_err(|err| format!("{}: {}", dir.display(), err))?;
+    // Editors often replace a file rather than write to it, so watch the input's directory
+    let input_dir = input.parent().expect("a file has a parent");
+    if !input_dir.starts_with(&dir) {
+        watcher
+            .watch(input_dir, RecursiveMode::NonRecursive)
+            .map_err(|err| format!("{}: {}", input_dir.display(), err))?;
+    }
+
+    println!(
+        "Watching {} and {}, Ctrl-C to stop",
+        relative(&dir),
+        relative(&input)
+    );
+    let mut previous = run_day(&name, &dir, &input, &[None, None]);
+    if !args.no_tests {
+        run_examples(&name);
+    }
+
+    loop {
+        // Wait for a change that matters, then for everything to settle
+        let mut changed = Vec::new();
+        while changed.is_empty() {
+            let event = receiver
+                .recv()
+                .map_err(|_| "stopped watching for changes")?;
+            collect(event, &dir, &input, &mut changed);
+        }
+        while let Ok(event) = receiver.recv_timeout(DEBOUNCE) {
+            collect(event, &dir, &input, &mut changed);
+        }
+
+        changed.sort();
+        changed.dedup();
+        let changed: Vec<String> = changed.iter().map(|path| relative(path)).collect();
+        println!();
+        println!("Changed: {}", changed.join(", "));
+        let answers = run_day(&name, &dir, &input, &previous);
+        // Keep comparing against the last answers the day gave, across failed builds
+        if answers.iter().any(Option::is_some) {
+            previous = answers;
+        }
+        if !args.no_tests {
+            run_examples(&name);
+        }
+    }
+}
+
+/// Adds the files that `event` changed to `changed`, if they are the day's code, tests or inputs.
+fn collect(
+    event: notify::Result<notify::Event>,
+    dir: &Path,
+    input: &Path,
+    changed: &mut Vec<PathBuf>,
+) {
+    let Ok(event) = event else {
+        return;
+    };
+    if !matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    ) {
+        return;
+    }
+    for path in event.paths {
+        if is_relevant(&path, dir, input) {
+            changed.push(path);
+        }
+    }
+}
+
+fn is_relevant(path: &Path, dir: &Path, input: &Path) -> bool {
+    if path == input {
+        return true;
+    }
+    // Skip the temporary and backup files editors leave next to the ones being edited
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    if name.starts_with('.') || name.ends_with('~') || name.ends_with(".swp") {
+        return false;
+    }
+    match path.strip_prefix(dir) {
+        Ok(path) => {
+            path == Path::new("Cargo.toml")
+                || ["src", "tests", "fixtures"]
+                    .iter()
+                    .any(|sub| path.starts_with(sub))
+        }
+        Err(_) => false,
+    }
+}
+
+/// `path` relative to the workspace root, or as is if it is outside of it.
+fn relative(path: &Path) -> String {
+    let root = workspace_root()
+        .canonicalize()
+        .unwrap_or_else(|_| workspace_root());
+    path.strip_prefix(&root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+fn cargo() -> Command {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut command = Command::new(cargo);
+    command.current_dir(workspace_root());
+    command
+}
+
+/// Builds and runs the day on `input`, and prints its answers next to the `previous` ones.
+/// Compiler errors and anything the day itself reports go straight to stderr.
+fn run_day(name: &str, dir: &Path, input: &Path, previous: &Answers) -> Answers {
+    let output = cargo()
+        .current_dir(dir)
+        .args(["run", "--release", "--quiet", "-p", name, "--"])
+        .args(["--format", "json"])
+        .arg(input)
+        .stderr(Stdio::inherit())
+        .output();
+    let output = match output {
+        Ok(output) => output,
+        Err(err) => {
+            println!("Failed to run cargo: {}", err);
+            return [None, None];
+        }
+    };
+    if !output.status.success() {
+        println!("{} failed ({})", name, output.status);
+        return [None, None];
+    }
+
+    let mut answers: Answers = [None, None];
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let Ok(record) = serde_json::from_str::<Record>(line) else {
+            continue;
+        };
+        let answer = match record.answer {
+            serde_json::Value::String(text) => text,
+            serde_json::Value::Null => "no solution".to_string(),
+            value => value.to_string(),
+        };
+        if let Some(slot) = answers.get_mut(record.part as usize - 1) {
+            *slot = Some(answer);
+        }
+    }
+
+    for (idx, (answer, previous)) in answers.iter().zip(previous).enumerate() {
+        let Some(answer) = answer else {
+            continue;
+        };
+        match previous {
+            None => println!("Part {}: {}", idx + 1, answer),
+            Some(previous) if previous == answer => {
+                println!("Part {}: {} (unchanged)", idx + 1, answer)
+            }
+            Some(previous) => println!("Part {}: {} (was {})", idx + 1, answer, previous),
+        }
+    }
+    answers
+}
+
+/// Runs the day's example tests, only showing their output if they fail.
+fn run_examples(name: &str) {
+    let output = cargo()
+        .args(["test", "--quiet", "-p", name, "--test", "examples"])
+        .output();
+    match output {
+        Ok(output) if output.status.success() => println!("Examples: ok"),
+        Ok(output) => {
+            print!("{}", String::from_utf8_lossy(&output.stdout));
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+            println!("Examples: failed");
+        }
+        Err(err) => println!("Failed to run cargo: {}", err),
+    }
+}